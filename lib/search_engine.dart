@@ -2,3 +2,4 @@ library search_engine;
 
 export 'src/rust/api/search_engine.dart';
 export 'src/rust/frb_generated.dart' show RustLib;
+export 'src/search_engine_exception.dart';
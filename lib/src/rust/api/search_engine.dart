@@ -6,11 +6,35 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`
+// These functions are ignored because they are not marked as `pub`: `add_neighbors`, `alternatives`, `ast_node`, `ast_text`, `books_query`, `category_facet`, `clause_query`, `collect_results`, `create_gematria_query`, `create_proximity_query`, `field_node`, `fields_named`, `filter_clause`, `first`, `fuzzy_literals`, `fuzzy_terms_in`, `fuzzy_terms`, `fuzzy_words`, `highlight_html`, `in_books`, `in_match_fields`, `invalid_query`, `leaf_node`, `leaf_text`, `literal_node`, `match_fields`, `matchable_terms`, `new`, `new`, `ordered_phrases`, `parse_query`, `proximity_node`, `proximity_phrases`, `query_parser`, `query_terms`, `rank`, `rewrite_query`, `score`, `search_result`, `snippet_field`, `snippet_terms`, `snippet`, `snippets`, `term_text`, `terms`, `top_docs`
+// These types are ignored because they are not used by any `pub` functions: `Hits`, `Page`, `QueryExplainer`, `Ranked`, `SnippetTerms`, `Snippets`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `partial_cmp`
 
 String testBindings({required String name}) =>
     RustLib.instance.api.crateApiSearchEngineTestBindings(name: name);
 
+/// Loads the abbreviation dictionary used to expand queries, replacing the
+/// one loaded before, and returns its number of entries.
+///
+/// The file is UTF-8 text with one abbreviation per line, followed by `=` and
+/// its expansions separated by `|`, e.g. `רש"י = רבי שלמה יצחקי`. Blank
+/// lines and lines starting with `#` are ignored.
+Future<int> loadAbbreviations({required String path}) =>
+    RustLib.instance.api.crateApiSearchEngineLoadAbbreviations(path: path);
+
+/// Loads a list of known roots, one per line, that limits the candidate roots
+/// indexed for root matching, and returns its number of roots. Without it
+/// roots are derived by rule alone. Only documents added afterwards are
+/// affected, so load it before indexing.
+Future<int> loadRootLexicon({required String path}) =>
+    RustLib.instance.api.crateApiSearchEngineLoadRootLexicon(path: path);
+
+/// The value of a Hebrew numeral such as "קכ"ג" or "קכג", or of a number
+/// written in digits, e.g. for filtering by chapter or page. Returns `None`
+/// when `text` is not a number.
+int? parseHebrewNumeral({required String text}) =>
+    RustLib.instance.api.crateApiSearchEngineParseHebrewNumeral(text: text);
+
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Box < dyn Query >>>
 abstract class BoxQuery implements RustOpaqueInterface {}
 
@@ -32,56 +56,914 @@ abstract class SearchEngine implements RustOpaqueInterface {
   static Future<BoxQuery> createSearchQuery(
           {required Index index,
           required String searchTerm,
-          required List<String> bookTitles,
-          required bool fuzzy}) =>
+          required BookFilter books,
+          required bool fuzzy,
+          Proximity? proximity,
+          required MatchOptions options}) =>
       RustLib.instance.api.crateApiSearchEngineSearchEngineCreateSearchQuery(
           index: index,
           searchTerm: searchTerm,
-          bookTitles: bookTitles,
-          fuzzy: fuzzy);
+          books: books,
+          fuzzy: fuzzy,
+          proximity: proximity,
+          options: options);
+
+  /// Compiles `query` into a query restricted to `books`. Its words are
+  /// analyzed according to the match options like those of a query string,
+  /// but never parsed, so any input is valid.
+  static Future<BoxQuery> createStructuredQuery(
+          {required Index index,
+          required SearchQuery query,
+          required BookFilter books,
+          required MatchOptions options}) => RustLib.instance.api
+      .crateApiSearchEngineSearchEngineCreateStructuredQuery(
+          index: index, query: query, books: books, options: options);
+
+  /// Describes the query `create_search_query` builds from `search_term`
+  /// with the same arguments, for showing the user how it was read: which
+  /// words are required or excluded, which are looked up as phrases, which
+  /// indexed words fuzzy words match and, for an invalid query, why.
+  Future<QueryExplanation> explainQuery(
+      {required String searchTerm,
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options});
+
+  /// Explains the score of the result with id `id` of the search
+  /// `create_search_query` builds from the other arguments, for finding
+  /// out why it ranks where it does. `None` when it is not a result.
+  Future<ScoreExplanation?> explainScore(
+      {required String query,
+      required BookFilter books,
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options,
+      required BigInt id});
+
+  /// Finds every match of `query`, searched for like `search` does with
+  /// the same arguments, in the segments of the book titled `title` from
+  /// `segment_start` up to `segment_end`, excluded, for highlighting them
+  /// all when the book is opened. Segments the query does not match are
+  /// left out, and in the others matches are found the way snippets
+  /// highlight them, fuzzy matches and other forms of the words included.
+  Future<List<SegmentHighlights>> highlightInDocument(
+      {required String query,
+      required String title,
+      required BigInt segmentStart,
+      required BigInt segmentEnd,
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options});
 
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
   static Future<SearchEngine> newInstance({required String path}) =>
       RustLib.instance.api.crateApiSearchEngineSearchEngineNew(path: path);
 
-  Future<List<SearchResult>> search(
+  /// Searches `books` for `query` and returns `limit` results in `order`,
+  /// after skipping `offset` results, together with the number of results
+  /// overall, per book and per category. With `after`, the cursor of the
+  /// last result of a page, the results following it are returned, so the
+  /// next page costs no more than the first. Each result comes with the
+  /// `context_segments` segments before and after it in its book.
+  Future<SearchResponse> search(
       {required String query,
-      required List<String> books,
+      required BookFilter books,
       required int limit,
-      required bool fuzzy});
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options,
+      required SnippetOptions snippetOptions,
+      required int contextSegments,
+      required SortOrder order,
+      required int offset,
+      SearchCursor? after});
 
+  /// Finds words, and runs of consecutive words, whose gematria is
+  /// `value`, with the matching words highlighted. Results come in
+  /// document order.
+  Future<SearchResponse> searchGematria(
+      {required int value,
+      required BookFilter books,
+      required int limit,
+      required GematriaOptions options,
+      required SnippetOptions snippetOptions});
+
+  /// Searches like `search`, sending the results found so far to `sink`
+  /// after each result, with snippets made with the default options.
   Stream<List<SearchResult>> searchStream(
       {required String query,
-      required List<String> books,
+      required BookFilter books,
       required int limit,
-      required bool fuzzy});
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options,
+      required SortOrder order});
+
+  /// Searches with a query assembled from parts, see [`SearchQuery`].
+  /// Results come in `order`, with their matches highlighted.
+  Future<SearchResponse> searchStructured(
+      {required SearchQuery query,
+      required BookFilter books,
+      required int limit,
+      required MatchOptions options,
+      required SnippetOptions snippetOptions,
+      required SortOrder order});
+}
+
+/// The number of segments of a book matching a search.
+
+class BookCount {
+  final String title;
+  final BigInt count;
+
+  const BookCount({
+    required this.title,
+    required this.count,
+  });
+
+  @override
+  int get hashCode => title.hashCode ^ count.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookCount &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          count == other.count;
+}
+
+/// The books a search looks in: all of them or those included by title or
+/// by category, less those excluded by either. Categories are paths of
+/// folders of the library, such as `אוצריא/תלמוד בבלי`, and take in the
+/// categories below them.
+
+class BookFilter {
+  /// Looks in every book not excluded, ignoring the books included.
+  final bool allBooks;
+  final List<String> includeTitles;
+  final List<String> includeCategories;
+  final List<String> excludeTitles;
+  final List<String> excludeCategories;
+
+  const BookFilter({
+    required this.allBooks,
+    required this.includeTitles,
+    required this.includeCategories,
+    required this.excludeTitles,
+    required this.excludeCategories,
+  });
+
+  static Future<BookFilter> default_() =>
+      RustLib.instance.api.crateApiSearchEngineBookFilterDefault();
+
+  @override
+  int get hashCode =>
+      allBooks.hashCode ^
+      includeTitles.hashCode ^
+      includeCategories.hashCode ^
+      excludeTitles.hashCode ^
+      excludeCategories.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookFilter &&
+          runtimeType == other.runtimeType &&
+          allBooks == other.allBooks &&
+          includeTitles == other.includeTitles &&
+          includeCategories == other.includeCategories &&
+          excludeTitles == other.excludeTitles &&
+          excludeCategories == other.excludeCategories;
+}
+
+/// The number of segments in a category of the library matching a search.
+
+class CategoryCount {
+  /// The folders of the category, separated by `/`, as in
+  /// `אוצריא/תלמוד בבלי/סדר מועד`.
+  final String category;
+  final BigInt count;
+
+  const CategoryCount({
+    required this.category,
+    required this.count,
+  });
+
+  @override
+  int get hashCode => category.hashCode ^ count.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CategoryCount &&
+          runtimeType == other.runtimeType &&
+          category == other.category &&
+          count == other.count;
+}
+
+/// Whether results have to match a [`QueryClause`].
+
+enum ClauseOccur {
+  /// The clause may match, and results matching more such clauses rank
+  /// higher. Without required clauses, at least one has to match.
+  should,
+  /// The clause has to match.
+  must,
+  /// The clause must not match.
+  mustNot,
+  ;
+}
+
+/// Restricts a [`SearchQuery`] to results whose `field` matches one of
+/// `values`, or to the other results when `exclude` is set.
+
+class FieldFilter {
+  final FilterField field;
+  final List<String> values;
+  final bool exclude;
+
+  const FieldFilter({
+    required this.field,
+    required this.values,
+    required this.exclude,
+  });
+
+  @override
+  int get hashCode => field.hashCode ^ values.hashCode ^ exclude.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FieldFilter &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          values == other.values &&
+          exclude == other.exclude;
+}
+
+/// Fields a [`FieldFilter`] can restrict.
+
+enum FilterField {
+  /// Book titles, compared whole.
+  title,
+  /// File paths, containing any word of the values.
+  filePath,
+  ;
+}
+
+/// Letter values used to compute gematria.
+
+enum GematriaMethod {
+  /// מספר הכרחי: א=1 up to ת=400, final letters counting as regular ones.
+  standard,
+  /// מספר קטן: the standard value without its zeros, so י=1 and ק=1.
+  small,
+  /// מספר גדול: final letters continue the count, ך=500 up to ץ=900.
+  large,
+  ;
+}
+
+/// What `search_gematria` looks for besides the value itself.
+
+class GematriaOptions {
+  final GematriaMethod method;
+  /// Fewest consecutive words whose total value may match, at least 1.
+  final int minWords;
+  /// Most consecutive words whose total value may match, at most 3.
+  final int maxWords;
+
+  const GematriaOptions({
+    required this.method,
+    required this.minWords,
+    required this.maxWords,
+  });
+
+  @override
+  int get hashCode => method.hashCode ^ minWords.hashCode ^ maxWords.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GematriaOptions &&
+          runtimeType == other.runtimeType &&
+          method == other.method &&
+          minWords == other.minWords &&
+          maxWords == other.maxWords;
+}
+
+/// A matched part of the text of a [`SearchResult`].
+
+class Highlight {
+  /// Where the part starts in the text, in bytes of UTF-8.
+  final int byteStart;
+  final int byteEnd;
+  /// Where the part starts in the text, in characters.
+  final int charStart;
+  final int charEnd;
+  /// The query term the part matched. For proximity searches, which
+  /// highlight a run of nearby words as one part, the terms of its words
+  /// separated by spaces.
+  final String term;
+
+  const Highlight({
+    required this.byteStart,
+    required this.byteEnd,
+    required this.charStart,
+    required this.charEnd,
+    required this.term,
+  });
+
+  @override
+  int get hashCode =>
+      byteStart.hashCode ^
+      byteEnd.hashCode ^
+      charStart.hashCode ^
+      charEnd.hashCode ^
+      term.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Highlight &&
+          runtimeType == other.runtimeType &&
+          byteStart == other.byteStart &&
+          byteEnd == other.byteEnd &&
+          charStart == other.charStart &&
+          charEnd == other.charEnd &&
+          term == other.term;
+}
+
+/// Markup opening and closing a highlight, such as `<b>` and `</b>`.
+
+class HighlightTags {
+  final String open;
+  final String close;
+
+  const HighlightTags({
+    required this.open,
+    required this.close,
+  });
+
+  @override
+  int get hashCode => open.hashCode ^ close.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HighlightTags &&
+          runtimeType == other.runtimeType &&
+          open == other.open &&
+          close == other.close;
+}
+
+/// Per-query switches controlling how the words of a query match the words
+/// of the indexed text.
+
+class MatchOptions {
+  /// Also match words carrying prefix letters (ו/ה/ב/כ/ל/מ/ש), so "תורה"
+  /// finds "והתורה" and "שבתורה".
+  final bool prefixInsensitive;
+  /// Also match other plene and defective spellings (כתיב מלא/חסר) of a
+  /// word, so "שלום" finds "שלם" and "דוד" finds "דויד". These matches
+  /// rank below matches of the spelling searched for.
+  final bool spellingInsensitive;
+  /// Read the query words as roots (שורשים) and match every inflection of
+  /// them, so "שמר" finds "שומר", "נשמרו" and "משמרת". Fuzzy query strings
+  /// cannot be read this way.
+  final bool byRoot;
+  /// Treat the ways of writing a divine name as one word, so "ה'" also
+  /// finds "יקוק", "ידוד" and "י-ה-ו-ה", and "אלקים" finds "אלהים" and
+  /// "אלוקים". Off, each spelling only finds itself.
+  final bool divineNames;
+
+  const MatchOptions({
+    required this.prefixInsensitive,
+    required this.spellingInsensitive,
+    required this.byRoot,
+    required this.divineNames,
+  });
+
+  static Future<MatchOptions> default_() =>
+      RustLib.instance.api.crateApiSearchEngineMatchOptionsDefault();
+
+  @override
+  int get hashCode =>
+      prefixInsensitive.hashCode ^
+      spellingInsensitive.hashCode ^
+      byRoot.hashCode ^
+      divineNames.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MatchOptions &&
+          runtimeType == other.runtimeType &&
+          prefixInsensitive == other.prefixInsensitive &&
+          spellingInsensitive == other.spellingInsensitive &&
+          byRoot == other.byRoot &&
+          divineNames == other.divineNames;
+}
+
+/// A segment next to a result in its book, with its whole text.
+
+class NeighborSegment {
+  final BigInt segment;
+  final String text;
+
+  const NeighborSegment({
+    required this.segment,
+    required this.text,
+  });
+
+  @override
+  int get hashCode => segment.hashCode ^ text.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NeighborSegment &&
+          runtimeType == other.runtimeType &&
+          segment == other.segment &&
+          text == other.text;
+}
+
+/// Finds the words of a query near each other rather than anywhere in a
+/// segment. Its words are matched exactly: a search near each other cannot
+/// also be fuzzy.
+
+class Proximity {
+  /// Most words allowed between a query word and the one before it. An
+  /// ordered search of more than two words rejects distances too long for
+  /// its number of words, see `MAX_PROXIMITY_PHRASES`.
+  final int maxDistance;
+  /// Whether the words have to appear in the order they were typed.
+  final bool ordered;
+
+  const Proximity({
+    required this.maxDistance,
+    required this.ordered,
+  });
+
+  @override
+  int get hashCode => maxDistance.hashCode ^ ordered.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Proximity &&
+          runtimeType == other.runtimeType &&
+          maxDistance == other.maxDistance &&
+          ordered == other.ordered;
+}
+
+/// A word, a phrase or a group of nearby words of a [`SearchQuery`].
+
+class QueryClause {
+  /// The words to look for, one for a single word and several for a phrase
+  /// or a proximity group. Words are analyzed like query text, so an entry
+  /// holding several words counts as several.
+  final List<String> words;
+  final ClauseOccur occur;
+  /// Typos tolerated in a single word, at most 2. Phrases and proximity
+  /// groups are matched exactly.
+  final int fuzziness;
+  /// How near each other several words have to be. `None` looks for them
+  /// as an exact phrase.
+  final Proximity? proximity;
+
+  const QueryClause({
+    required this.words,
+    required this.occur,
+    required this.fuzziness,
+    this.proximity,
+  });
+
+  @override
+  int get hashCode =>
+      words.hashCode ^ occur.hashCode ^ fuzziness.hashCode ^ proximity.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QueryClause &&
+          runtimeType == other.runtimeType &&
+          words == other.words &&
+          occur == other.occur &&
+          fuzziness == other.fuzziness &&
+          proximity == other.proximity;
+}
+
+/// Words of a query searched together with their dictionary alternatives,
+/// e.g. "רש״י" together with "רבי שלמה יצחקי".
+
+class QueryExpansion {
+  /// The words as they appear in the query.
+  final String original;
+  /// The abbreviations or expansions searched in addition to them.
+  final List<String> alternatives;
+
+  const QueryExpansion({
+    required this.original,
+    required this.alternatives,
+  });
+
+  @override
+  int get hashCode => original.hashCode ^ alternatives.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QueryExpansion &&
+          runtimeType == other.runtimeType &&
+          original == other.original &&
+          alternatives == other.alternatives;
+}
+
+/// How `create_search_query` interpreted a query string, for showing the
+/// user what was actually searched.
+
+class QueryExplanation {
+  /// The query text handed to the query parser after expansion.
+  final String parsedQuery;
+  /// Words of the query that were also searched in another form.
+  final List<QueryExpansion> expansions;
+  /// The query built, without the restriction to the chosen books. `None`
+  /// when the query is invalid.
+  final QueryNode? root;
+  /// Why the query is invalid, if it is.
+  final SearchEngineError? error;
+
+  const QueryExplanation({
+    required this.parsedQuery,
+    required this.expansions,
+    this.root,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      parsedQuery.hashCode ^
+      expansions.hashCode ^
+      root.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QueryExplanation &&
+          runtimeType == other.runtimeType &&
+          parsedQuery == other.parsedQuery &&
+          expansions == other.expansions &&
+          root == other.root &&
+          error == other.error;
+}
+
+/// A part of the query built by `create_search_query`, see `explain_query`.
+
+class QueryNode {
+  final QueryNodeKind kind;
+  /// Whether results have to match this part of the group holding it.
+  final ClauseOccur occur;
+  /// The field looked up, e.g. "text" or "skeleton". Empty for groups.
+  final String field;
+  /// The query text this part was built from.
+  final String text;
+  /// The terms looked up, as analyzed for `field`, or the bounds of a
+  /// range.
+  final List<String> terms;
+  /// Typos tolerated by a fuzzy word, positions the words of a phrase may
+  /// be out of place in total, or words allowed between those of a
+  /// proximity group.
+  final int distance;
+  /// Whether the words of a proximity group have to keep their order.
+  final bool ordered;
+  /// Weight of matches relative to those of the other parts.
+  final double boost;
+  /// For a fuzzy word, the indexed words it matches, at most 50.
+  final List<String> fuzzyExpansions;
+  /// The parts of a group.
+  final List<QueryNode> children;
+
+  const QueryNode({
+    required this.kind,
+    required this.occur,
+    required this.field,
+    required this.text,
+    required this.terms,
+    required this.distance,
+    required this.ordered,
+    required this.boost,
+    required this.fuzzyExpansions,
+    required this.children,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      occur.hashCode ^
+      field.hashCode ^
+      text.hashCode ^
+      terms.hashCode ^
+      distance.hashCode ^
+      ordered.hashCode ^
+      boost.hashCode ^
+      fuzzyExpansions.hashCode ^
+      children.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QueryNode &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          occur == other.occur &&
+          field == other.field &&
+          text == other.text &&
+          terms == other.terms &&
+          distance == other.distance &&
+          ordered == other.ordered &&
+          boost == other.boost &&
+          fuzzyExpansions == other.fuzzyExpansions &&
+          children == other.children;
+}
+
+/// What a [`QueryNode`] looks for.
+
+enum QueryNodeKind {
+  /// Its children, each as required by its `occur`.
+  group,
+  /// A single word.
+  term,
+  /// A word, allowing typos.
+  fuzzy,
+  /// Words one after another.
+  phrase,
+  /// Words near each other.
+  proximity,
+  /// Values between two bounds.
+  range,
+  /// Any of several words.
+  termSet,
+  /// Everything having a value in the field.
+  exists,
+  /// Everything.
+  all,
+  /// Nothing, for text without searchable words.
+  nothing,
+  ;
+}
+
+/// How the score of a result was computed, see `explain_score`.
+
+class ScoreExplanation {
+  final double value;
+  final String description;
+  /// The scores this one was computed from.
+  final List<ScoreExplanation> details;
+  /// Remarks on the computation, e.g. terms missing from the index.
+  final List<String> context;
+
+  const ScoreExplanation({
+    required this.value,
+    required this.description,
+    required this.details,
+    required this.context,
+  });
+
+  @override
+  int get hashCode =>
+      value.hashCode ^
+      description.hashCode ^
+      details.hashCode ^
+      context.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScoreExplanation &&
+          runtimeType == other.runtimeType &&
+          value == other.value &&
+          description == other.description &&
+          details == other.details &&
+          context == other.context;
+}
+
+/// The position of a result in the order of a search. Passed back as `after`,
+/// the same search resumes with the results following it. The index address
+/// breaking ties between equal results stays valid until the next commit, so
+/// pages fetched in between neither skip nor repeat results.
+
+class SearchCursor {
+  /// Relevance of the result.
+  final double score;
+  final BigInt id;
+  /// Title of the book, when sorting by title.
+  final String title;
+  final BigInt segment;
+  /// Index segment holding the result.
+  final int segmentOrd;
+  /// The result within its index segment.
+  final int docId;
+
+  const SearchCursor({
+    required this.score,
+    required this.id,
+    required this.title,
+    required this.segment,
+    required this.segmentOrd,
+    required this.docId,
+  });
+
+  @override
+  int get hashCode =>
+      score.hashCode ^
+      id.hashCode ^
+      title.hashCode ^
+      segment.hashCode ^
+      segmentOrd.hashCode ^
+      docId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchCursor &&
+          runtimeType == other.runtimeType &&
+          score == other.score &&
+          id == other.id &&
+          title == other.title &&
+          segment == other.segment &&
+          segmentOrd == other.segmentOrd &&
+          docId == other.docId;
+}
+
+/// Why a search engine call failed. In Dart it is thrown as a
+/// `SearchEngineError`, told apart by its `kind`; `withExceptions` on the
+/// futures and streams of the API rethrows it as the `SearchEngineException`
+/// subclass of its kind.
+
+class SearchEngineError implements FrbException {
+  final SearchEngineErrorKind kind;
+  final String message;
+  /// For an invalid query, the character offset of the first error in the
+  /// query as rewritten for the query parser, see
+  /// [`QueryExplanation::parsed_query`], when it is known.
+  final int? position;
+
+  const SearchEngineError({
+    required this.kind,
+    required this.message,
+    this.position,
+  });
+
+  @override
+  int get hashCode => kind.hashCode ^ message.hashCode ^ position.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchEngineError &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          message == other.message &&
+          position == other.position;
+}
+
+/// The kinds of [`SearchEngineError`].
+
+enum SearchEngineErrorKind {
+  /// Another writer, usually another instance of the app, holds the index.
+  indexLocked,
+  /// Index files are damaged or were written by an incompatible version.
+  corruptIndex,
+  /// The query could not be parsed or asks for more than is supported.
+  invalidQuery,
+  /// The index on disk was created with different fields; it has to be
+  /// deleted and rebuilt.
+  schemaMismatch,
+  /// Reading or writing the index or a dictionary file failed.
+  io,
+  /// Any other failure.
+  other,
+  ;
+}
+
+/// A query assembled from parts instead of parsed from text. Its words are
+/// always taken literally, so quotes, colons or parentheses typed by the user
+/// can never be misread as query syntax.
+
+class SearchQuery {
+  /// Words, phrases and groups of nearby words to look for.
+  final List<QueryClause> clauses;
+  /// Restrictions on the fields of the results, all of which they meet.
+  final List<FieldFilter> filters;
+
+  const SearchQuery({
+    required this.clauses,
+    required this.filters,
+  });
+
+  @override
+  int get hashCode => clauses.hashCode ^ filters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchQuery &&
+          runtimeType == other.runtimeType &&
+          clauses == other.clauses &&
+          filters == other.filters;
+}
+
+/// The results of a search, with the number of segments matching it.
+
+class SearchResponse {
+  /// The results asked for.
+  final List<SearchResult> hits;
+  /// Segments matching the search in all the books searched, beyond
+  /// those returned.
+  final BigInt totalCount;
+  /// Segments matching the search in each book, most first.
+  final List<BookCount> perBookCounts;
+  /// Segments matching the search in each category and the categories
+  /// below it, listing every category before those below it.
+  final List<CategoryCount> perCategoryCounts;
+
+  const SearchResponse({
+    required this.hits,
+    required this.totalCount,
+    required this.perBookCounts,
+    required this.perCategoryCounts,
+  });
+
+  @override
+  int get hashCode =>
+      hits.hashCode ^
+      totalCount.hashCode ^
+      perBookCounts.hashCode ^
+      perCategoryCounts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchResponse &&
+          runtimeType == other.runtimeType &&
+          hits == other.hits &&
+          totalCount == other.totalCount &&
+          perBookCounts == other.perBookCounts &&
+          perCategoryCounts == other.perCategoryCounts;
 }
 
 class SearchResult {
   final String title;
+  /// Plain text around the words matched, or the whole text of the
+  /// segment when there is nothing to highlight.
   final String text;
+  /// The matched parts of `text`, in order.
+  final List<Highlight> highlights;
+  /// `text` as HTML with the highlights marked, when the snippet options
+  /// ask for it.
+  final String? html;
   final BigInt id;
   final BigInt segment;
   final bool isPdf;
   final String filePath;
+  /// Where the result stands in the order of the search, for fetching the
+  /// results after it.
+  final SearchCursor cursor;
+  /// The segments of the book just before the result, in order, when the
+  /// search asks for context.
+  final List<NeighborSegment> preceding;
+  /// The segments of the book just after the result, in order.
+  final List<NeighborSegment> following;
 
   const SearchResult({
     required this.title,
     required this.text,
+    required this.highlights,
+    this.html,
     required this.id,
     required this.segment,
     required this.isPdf,
     required this.filePath,
+    required this.cursor,
+    required this.preceding,
+    required this.following,
   });
 
   @override
   int get hashCode =>
       title.hashCode ^
       text.hashCode ^
+      highlights.hashCode ^
+      html.hashCode ^
       id.hashCode ^
       segment.hashCode ^
       isPdf.hashCode ^
-      filePath.hashCode;
+      filePath.hashCode ^
+      cursor.hashCode ^
+      preceding.hashCode ^
+      following.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -90,8 +972,114 @@ class SearchResult {
           runtimeType == other.runtimeType &&
           title == other.title &&
           text == other.text &&
+          highlights == other.highlights &&
+          html == other.html &&
           id == other.id &&
           segment == other.segment &&
           isPdf == other.isPdf &&
-          filePath == other.filePath;
+          filePath == other.filePath &&
+          cursor == other.cursor &&
+          preceding == other.preceding &&
+          following == other.following;
+}
+
+/// The matches of a search in a segment, see `highlight_in_document`.
+
+class SegmentHighlights {
+  final BigInt segment;
+  /// The matched parts of the stored text of the segment, in order.
+  final List<Highlight> highlights;
+
+  const SegmentHighlights({
+    required this.segment,
+    required this.highlights,
+  });
+
+  @override
+  int get hashCode => segment.hashCode ^ highlights.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SegmentHighlights &&
+          runtimeType == other.runtimeType &&
+          segment == other.segment &&
+          highlights == other.highlights;
+}
+
+/// Where the fragments of a snippet start and end. Each is centered on the
+/// words it matches, with as much text before them as after.
+
+enum SnippetBoundary {
+  /// Anywhere, even within a word.
+  none,
+  /// Between words.
+  word,
+  /// At the ends of the sentences holding the matched words, when they
+  /// fit in the fragment, otherwise between words.
+  sentence,
+  ;
+}
+
+/// How the snippets of results are made: up to `fragments` parts of the
+/// text around the words matched, each of at most `max_chars` characters,
+/// joined by `separator`.
+
+class SnippetOptions {
+  final int maxChars;
+  /// Most fragments in a snippet, the best scoring ones, at least 1.
+  final int fragments;
+  /// Text put between fragments, such as " … ".
+  final String separator;
+  final SnippetBoundary boundary;
+  /// Markup put around the highlights in `SearchResult::html`. Without
+  /// it, results come without HTML.
+  final HighlightTags? htmlTags;
+
+  const SnippetOptions({
+    required this.maxChars,
+    required this.fragments,
+    required this.separator,
+    required this.boundary,
+    this.htmlTags,
+  });
+
+  static Future<SnippetOptions> default_() =>
+      RustLib.instance.api.crateApiSearchEngineSnippetOptionsDefault();
+
+  @override
+  int get hashCode =>
+      maxChars.hashCode ^
+      fragments.hashCode ^
+      separator.hashCode ^
+      boundary.hashCode ^
+      htmlTags.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SnippetOptions &&
+          runtimeType == other.runtimeType &&
+          maxChars == other.maxChars &&
+          fragments == other.fragments &&
+          separator == other.separator &&
+          boundary == other.boundary &&
+          htmlTags == other.htmlTags;
+}
+
+/// The order results come in.
+
+enum SortOrder {
+  /// Best matches first.
+  relevance,
+  /// The order of the library, by `id`.
+  libraryOrder,
+  /// The order of the library, from its end.
+  libraryOrderDescending,
+  /// By book title, then by segment within the book.
+  title,
+  /// By book title from the end of the alphabet, then by segment from the
+  /// end of the book.
+  titleDescending,
+  ;
 }
@@ -66,7 +66,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.3.0';
 
   @override
-  int get rustContentHash => -340198064;
+  int get rustContentHash => -1880642976;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -92,25 +92,99 @@ abstract class RustLibApi extends BaseApi {
   Future<BoxQuery> crateApiSearchEngineSearchEngineCreateSearchQuery(
       {required Index index,
       required String searchTerm,
-      required List<String> bookTitles,
-      required bool fuzzy});
+      required BookFilter books,
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options});
+
+  Future<BoxQuery> crateApiSearchEngineSearchEngineCreateStructuredQuery(
+      {required Index index,
+      required SearchQuery query,
+      required BookFilter books,
+      required MatchOptions options});
+
+  Future<QueryExplanation> crateApiSearchEngineSearchEngineExplainQuery(
+      {required SearchEngine that,
+      required String searchTerm,
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options});
+
+  Future<ScoreExplanation?> crateApiSearchEngineSearchEngineExplainScore(
+      {required SearchEngine that,
+      required String query,
+      required BookFilter books,
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options,
+      required BigInt id});
+
+  Future<List<SegmentHighlights>>
+      crateApiSearchEngineSearchEngineHighlightInDocument(
+          {required SearchEngine that,
+          required String query,
+          required String title,
+          required BigInt segmentStart,
+          required BigInt segmentEnd,
+          required bool fuzzy,
+          Proximity? proximity,
+          required MatchOptions options});
 
   Future<SearchEngine> crateApiSearchEngineSearchEngineNew(
       {required String path});
 
-  Future<List<SearchResult>> crateApiSearchEngineSearchEngineSearch(
+  Future<SearchResponse> crateApiSearchEngineSearchEngineSearch(
       {required SearchEngine that,
       required String query,
-      required List<String> books,
+      required BookFilter books,
+      required int limit,
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options,
+      required SnippetOptions snippetOptions,
+      required int contextSegments,
+      required SortOrder order,
+      required int offset,
+      SearchCursor? after});
+
+  Future<SearchResponse> crateApiSearchEngineSearchEngineSearchGematria(
+      {required SearchEngine that,
+      required int value,
+      required BookFilter books,
       required int limit,
-      required bool fuzzy});
+      required GematriaOptions options,
+      required SnippetOptions snippetOptions});
 
   Stream<List<SearchResult>> crateApiSearchEngineSearchEngineSearchStream(
       {required SearchEngine that,
       required String query,
-      required List<String> books,
+      required BookFilter books,
+      required int limit,
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options,
+      required SortOrder order});
+
+  Future<SearchResponse> crateApiSearchEngineSearchEngineSearchStructured(
+      {required SearchEngine that,
+      required SearchQuery query,
+      required BookFilter books,
       required int limit,
-      required bool fuzzy});
+      required MatchOptions options,
+      required SnippetOptions snippetOptions,
+      required SortOrder order});
+
+  Future<BookFilter> crateApiSearchEngineBookFilterDefault();
+
+  Future<int> crateApiSearchEngineLoadAbbreviations({required String path});
+
+  Future<int> crateApiSearchEngineLoadRootLexicon({required String path});
+
+  Future<MatchOptions> crateApiSearchEngineMatchOptionsDefault();
+
+  int? crateApiSearchEngineParseHebrewNumeral({required String text});
+
+  Future<SnippetOptions> crateApiSearchEngineSnippetOptionsDefault();
 
   String crateApiSearchEngineTestBindings({required String name});
 
@@ -170,7 +244,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: sse_decode_search_engine_error,
       ),
       constMeta: kCrateApiSearchEngineSearchEngineAddDocumentConstMeta,
       argValues: [that, id, title, text, segment, isPdf, filePath],
@@ -205,7 +279,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: sse_decode_search_engine_error,
       ),
       constMeta: kCrateApiSearchEngineSearchEngineCommitConstMeta,
       argValues: [that],
@@ -223,26 +297,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Future<BoxQuery> crateApiSearchEngineSearchEngineCreateSearchQuery(
       {required Index index,
       required String searchTerm,
-      required List<String> bookTitles,
-      required bool fuzzy}) {
+      required BookFilter books,
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex(
             index, serializer);
         sse_encode_String(searchTerm, serializer);
-        sse_encode_list_String(bookTitles, serializer);
+        sse_encode_box_autoadd_book_filter(books, serializer);
         sse_encode_bool(fuzzy, serializer);
+        sse_encode_opt_box_autoadd_proximity(proximity, serializer);
+        sse_encode_box_autoadd_match_options(options, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 3, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
             sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: sse_decode_search_engine_error,
       ),
       constMeta: kCrateApiSearchEngineSearchEngineCreateSearchQueryConstMeta,
-      argValues: [index, searchTerm, bookTitles, fuzzy],
+      argValues: [index, searchTerm, books, fuzzy, proximity, options],
       apiImpl: this,
     ));
   }
@@ -251,7 +329,193 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       get kCrateApiSearchEngineSearchEngineCreateSearchQueryConstMeta =>
           const TaskConstMeta(
             debugName: "SearchEngine_create_search_query",
-            argNames: ["index", "searchTerm", "bookTitles", "fuzzy"],
+            argNames: [
+              "index",
+              "searchTerm",
+              "books",
+              "fuzzy",
+              "proximity",
+              "options"
+            ],
+          );
+
+  @override
+  Future<BoxQuery> crateApiSearchEngineSearchEngineCreateStructuredQuery(
+      {required Index index,
+      required SearchQuery query,
+      required BookFilter books,
+      required MatchOptions options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex(
+            index, serializer);
+        sse_encode_box_autoadd_search_query(query, serializer);
+        sse_encode_box_autoadd_book_filter(books, serializer);
+        sse_encode_box_autoadd_match_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 4, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery,
+        decodeErrorData: sse_decode_search_engine_error,
+      ),
+      constMeta:
+          kCrateApiSearchEngineSearchEngineCreateStructuredQueryConstMeta,
+      argValues: [index, query, books, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiSearchEngineSearchEngineCreateStructuredQueryConstMeta =>
+          const TaskConstMeta(
+            debugName: "SearchEngine_create_structured_query",
+            argNames: ["index", "query", "books", "options"],
+          );
+
+  @override
+  Future<QueryExplanation> crateApiSearchEngineSearchEngineExplainQuery(
+      {required SearchEngine that,
+      required String searchTerm,
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+            that, serializer);
+        sse_encode_String(searchTerm, serializer);
+        sse_encode_bool(fuzzy, serializer);
+        sse_encode_opt_box_autoadd_proximity(proximity, serializer);
+        sse_encode_box_autoadd_match_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 5, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_query_explanation,
+        decodeErrorData: sse_decode_search_engine_error,
+      ),
+      constMeta: kCrateApiSearchEngineSearchEngineExplainQueryConstMeta,
+      argValues: [that, searchTerm, fuzzy, proximity, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSearchEngineSearchEngineExplainQueryConstMeta =>
+      const TaskConstMeta(
+        debugName: "SearchEngine_explain_query",
+        argNames: ["that", "searchTerm", "fuzzy", "proximity", "options"],
+      );
+
+  @override
+  Future<ScoreExplanation?> crateApiSearchEngineSearchEngineExplainScore(
+      {required SearchEngine that,
+      required String query,
+      required BookFilter books,
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options,
+      required BigInt id}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+            that, serializer);
+        sse_encode_String(query, serializer);
+        sse_encode_box_autoadd_book_filter(books, serializer);
+        sse_encode_bool(fuzzy, serializer);
+        sse_encode_opt_box_autoadd_proximity(proximity, serializer);
+        sse_encode_box_autoadd_match_options(options, serializer);
+        sse_encode_u_64(id, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 6, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_score_explanation,
+        decodeErrorData: sse_decode_search_engine_error,
+      ),
+      constMeta: kCrateApiSearchEngineSearchEngineExplainScoreConstMeta,
+      argValues: [that, query, books, fuzzy, proximity, options, id],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSearchEngineSearchEngineExplainScoreConstMeta =>
+      const TaskConstMeta(
+        debugName: "SearchEngine_explain_score",
+        argNames: [
+          "that",
+          "query",
+          "books",
+          "fuzzy",
+          "proximity",
+          "options",
+          "id"
+        ],
+      );
+
+  @override
+  Future<List<SegmentHighlights>>
+      crateApiSearchEngineSearchEngineHighlightInDocument(
+          {required SearchEngine that,
+          required String query,
+          required String title,
+          required BigInt segmentStart,
+          required BigInt segmentEnd,
+          required bool fuzzy,
+          Proximity? proximity,
+          required MatchOptions options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+            that, serializer);
+        sse_encode_String(query, serializer);
+        sse_encode_String(title, serializer);
+        sse_encode_u_64(segmentStart, serializer);
+        sse_encode_u_64(segmentEnd, serializer);
+        sse_encode_bool(fuzzy, serializer);
+        sse_encode_opt_box_autoadd_proximity(proximity, serializer);
+        sse_encode_box_autoadd_match_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 7, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_segment_highlights,
+        decodeErrorData: sse_decode_search_engine_error,
+      ),
+      constMeta: kCrateApiSearchEngineSearchEngineHighlightInDocumentConstMeta,
+      argValues: [
+        that,
+        query,
+        title,
+        segmentStart,
+        segmentEnd,
+        fuzzy,
+        proximity,
+        options
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiSearchEngineSearchEngineHighlightInDocumentConstMeta =>
+          const TaskConstMeta(
+            debugName: "SearchEngine_highlight_in_document",
+            argNames: [
+              "that",
+              "query",
+              "title",
+              "segmentStart",
+              "segmentEnd",
+              "fuzzy",
+              "proximity",
+              "options"
+            ],
           );
 
   @override
@@ -262,12 +526,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
+            funcId: 8, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData:
             sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine,
-        decodeErrorData: null,
+        decodeErrorData: sse_decode_search_engine_error,
       ),
       constMeta: kCrateApiSearchEngineSearchEngineNewConstMeta,
       argValues: [path],
@@ -282,30 +546,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<List<SearchResult>> crateApiSearchEngineSearchEngineSearch(
+  Future<SearchResponse> crateApiSearchEngineSearchEngineSearch(
       {required SearchEngine that,
       required String query,
-      required List<String> books,
+      required BookFilter books,
       required int limit,
-      required bool fuzzy}) {
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options,
+      required SnippetOptions snippetOptions,
+      required int contextSegments,
+      required SortOrder order,
+      required int offset,
+      SearchCursor? after}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
             that, serializer);
         sse_encode_String(query, serializer);
-        sse_encode_list_String(books, serializer);
+        sse_encode_box_autoadd_book_filter(books, serializer);
         sse_encode_u_32(limit, serializer);
         sse_encode_bool(fuzzy, serializer);
+        sse_encode_opt_box_autoadd_proximity(proximity, serializer);
+        sse_encode_box_autoadd_match_options(options, serializer);
+        sse_encode_box_autoadd_snippet_options(snippetOptions, serializer);
+        sse_encode_u_32(contextSegments, serializer);
+        sse_encode_sort_order(order, serializer);
+        sse_encode_u_32(offset, serializer);
+        sse_encode_opt_box_autoadd_search_cursor(after, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
+            funcId: 9, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_search_result,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_search_response,
+        decodeErrorData: sse_decode_search_engine_error,
       ),
       constMeta: kCrateApiSearchEngineSearchEngineSearchConstMeta,
-      argValues: [that, query, books, limit, fuzzy],
+      argValues: [
+        that,
+        query,
+        books,
+        limit,
+        fuzzy,
+        proximity,
+        options,
+        snippetOptions,
+        contextSegments,
+        order,
+        offset,
+        after
+      ],
       apiImpl: this,
     ));
   }
@@ -313,16 +604,76 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiSearchEngineSearchEngineSearchConstMeta =>
       const TaskConstMeta(
         debugName: "SearchEngine_search",
-        argNames: ["that", "query", "books", "limit", "fuzzy"],
+        argNames: [
+          "that",
+          "query",
+          "books",
+          "limit",
+          "fuzzy",
+          "proximity",
+          "options",
+          "snippetOptions",
+          "contextSegments",
+          "order",
+          "offset",
+          "after"
+        ],
+      );
+
+  @override
+  Future<SearchResponse> crateApiSearchEngineSearchEngineSearchGematria(
+      {required SearchEngine that,
+      required int value,
+      required BookFilter books,
+      required int limit,
+      required GematriaOptions options,
+      required SnippetOptions snippetOptions}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+            that, serializer);
+        sse_encode_u_32(value, serializer);
+        sse_encode_box_autoadd_book_filter(books, serializer);
+        sse_encode_u_32(limit, serializer);
+        sse_encode_box_autoadd_gematria_options(options, serializer);
+        sse_encode_box_autoadd_snippet_options(snippetOptions, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 10, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_search_response,
+        decodeErrorData: sse_decode_search_engine_error,
+      ),
+      constMeta: kCrateApiSearchEngineSearchEngineSearchGematriaConstMeta,
+      argValues: [that, value, books, limit, options, snippetOptions],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSearchEngineSearchEngineSearchGematriaConstMeta =>
+      const TaskConstMeta(
+        debugName: "SearchEngine_search_gematria",
+        argNames: [
+          "that",
+          "value",
+          "books",
+          "limit",
+          "options",
+          "snippetOptions"
+        ],
       );
 
   @override
   Stream<List<SearchResult>> crateApiSearchEngineSearchEngineSearchStream(
       {required SearchEngine that,
       required String query,
-      required List<String> books,
+      required BookFilter books,
       required int limit,
-      required bool fuzzy}) {
+      required bool fuzzy,
+      Proximity? proximity,
+      required MatchOptions options,
+      required SortOrder order}) {
     final sink = RustStreamSink<List<SearchResult>>();
     unawaited(handler.executeNormal(NormalTask(
       callFfi: (port_) {
@@ -331,18 +682,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that, serializer);
         sse_encode_String(query, serializer);
         sse_encode_StreamSink_list_search_result_Sse(sink, serializer);
-        sse_encode_list_String(books, serializer);
+        sse_encode_box_autoadd_book_filter(books, serializer);
         sse_encode_u_32(limit, serializer);
         sse_encode_bool(fuzzy, serializer);
+        sse_encode_opt_box_autoadd_proximity(proximity, serializer);
+        sse_encode_box_autoadd_match_options(options, serializer);
+        sse_encode_sort_order(order, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
+            funcId: 11, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeErrorData: sse_decode_search_engine_error,
       ),
       constMeta: kCrateApiSearchEngineSearchEngineSearchStreamConstMeta,
-      argValues: [that, query, sink, books, limit, fuzzy],
+      argValues: [
+        that,
+        query,
+        sink,
+        books,
+        limit,
+        fuzzy,
+        proximity,
+        options,
+        order
+      ],
       apiImpl: this,
     )));
     return sink.stream;
@@ -351,64 +715,268 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiSearchEngineSearchEngineSearchStreamConstMeta =>
       const TaskConstMeta(
         debugName: "SearchEngine_search_stream",
-        argNames: ["that", "query", "sink", "books", "limit", "fuzzy"],
+        argNames: [
+          "that",
+          "query",
+          "sink",
+          "books",
+          "limit",
+          "fuzzy",
+          "proximity",
+          "options",
+          "order"
+        ],
       );
 
   @override
-  String crateApiSearchEngineTestBindings({required String name}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
+  Future<SearchResponse> crateApiSearchEngineSearchEngineSearchStructured(
+      {required SearchEngine that,
+      required SearchQuery query,
+      required BookFilter books,
+      required int limit,
+      required MatchOptions options,
+      required SnippetOptions snippetOptions,
+      required SortOrder order}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(name, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+            that, serializer);
+        sse_encode_box_autoadd_search_query(query, serializer);
+        sse_encode_box_autoadd_book_filter(books, serializer);
+        sse_encode_u_32(limit, serializer);
+        sse_encode_box_autoadd_match_options(options, serializer);
+        sse_encode_box_autoadd_snippet_options(snippetOptions, serializer);
+        sse_encode_sort_order(order, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 12, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_search_response,
+        decodeErrorData: sse_decode_search_engine_error,
+      ),
+      constMeta: kCrateApiSearchEngineSearchEngineSearchStructuredConstMeta,
+      argValues: [that, query, books, limit, options, snippetOptions, order],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiSearchEngineSearchEngineSearchStructuredConstMeta =>
+          const TaskConstMeta(
+            debugName: "SearchEngine_search_structured",
+            argNames: [
+              "that",
+              "query",
+              "books",
+              "limit",
+              "options",
+              "snippetOptions",
+              "order"
+            ],
+          );
+
+  @override
+  Future<BookFilter> crateApiSearchEngineBookFilterDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 13, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_book_filter,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiSearchEngineTestBindingsConstMeta,
-      argValues: [name],
+      constMeta: kCrateApiSearchEngineBookFilterDefaultConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiSearchEngineTestBindingsConstMeta =>
+  TaskConstMeta get kCrateApiSearchEngineBookFilterDefaultConstMeta =>
       const TaskConstMeta(
-        debugName: "test_bindings",
-        argNames: ["name"],
+        debugName: "book_filter_default",
+        argNames: [],
       );
 
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_BoxQuery => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery;
+  @override
+  Future<int> crateApiSearchEngineLoadAbbreviations({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 14, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_search_engine_error,
+      ),
+      constMeta: kCrateApiSearchEngineLoadAbbreviationsConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
 
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_BoxQuery => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery;
+  TaskConstMeta get kCrateApiSearchEngineLoadAbbreviationsConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_abbreviations",
+        argNames: ["path"],
+      );
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Index =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex;
+  @override
+  Future<int> crateApiSearchEngineLoadRootLexicon({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 15, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_search_engine_error,
+      ),
+      constMeta: kCrateApiSearchEngineLoadRootLexiconConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Index =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex;
+  TaskConstMeta get kCrateApiSearchEngineLoadRootLexiconConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_root_lexicon",
+        argNames: ["path"],
+      );
 
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_SearchEngine => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine;
+  @override
+  Future<MatchOptions> crateApiSearchEngineMatchOptionsDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 16, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_match_options,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSearchEngineMatchOptionsDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
 
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_SearchEngine => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine;
+  TaskConstMeta get kCrateApiSearchEngineMatchOptionsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "match_options_default",
+        argNames: [],
+      );
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  @override
+  int? crateApiSearchEngineParseHebrewNumeral({required String text}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(text, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSearchEngineParseHebrewNumeralConstMeta,
+      argValues: [text],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  BoxQuery
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery(
+  TaskConstMeta get kCrateApiSearchEngineParseHebrewNumeralConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_hebrew_numeral",
+        argNames: ["text"],
+      );
+
+  @override
+  Future<SnippetOptions> crateApiSearchEngineSnippetOptionsDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 18, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_snippet_options,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSearchEngineSnippetOptionsDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSearchEngineSnippetOptionsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "snippet_options_default",
+        argNames: [],
+      );
+
+  @override
+  String crateApiSearchEngineTestBindings({required String name}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSearchEngineTestBindingsConstMeta,
+      argValues: [name],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSearchEngineTestBindingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "test_bindings",
+        argNames: ["name"],
+      );
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_BoxQuery => wire
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_BoxQuery => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Index =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Index =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex;
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_SearchEngine => wire
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_SearchEngine => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  BoxQuery
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery(
           dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return BoxQueryImpl.frbInternalDcoDecode(raw as List<dynamic>);
@@ -438,6 +1006,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return IndexImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  SearchEngine
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SearchEngineImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   BoxQuery
       dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery(
@@ -475,24 +1051,272 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as String;
   }
 
+  @protected
+  BookCount dco_decode_book_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BookCount(
+      title: dco_decode_String(arr[0]),
+      count: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  BookFilter dco_decode_book_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return BookFilter(
+      allBooks: dco_decode_bool(arr[0]),
+      includeTitles: dco_decode_list_String(arr[1]),
+      includeCategories: dco_decode_list_String(arr[2]),
+      excludeTitles: dco_decode_list_String(arr[3]),
+      excludeCategories: dco_decode_list_String(arr[4]),
+    );
+  }
+
   @protected
   bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as bool;
   }
 
+  @protected
+  BookFilter dco_decode_box_autoadd_book_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_book_filter(raw);
+  }
+
+  @protected
+  GematriaOptions dco_decode_box_autoadd_gematria_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_gematria_options(raw);
+  }
+
+  @protected
+  HighlightTags dco_decode_box_autoadd_highlight_tags(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_highlight_tags(raw);
+  }
+
+  @protected
+  MatchOptions dco_decode_box_autoadd_match_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_match_options(raw);
+  }
+
+  @protected
+  Proximity dco_decode_box_autoadd_proximity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_proximity(raw);
+  }
+
+  @protected
+  QueryNode dco_decode_box_autoadd_query_node(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_query_node(raw);
+  }
+
+  @protected
+  ScoreExplanation dco_decode_box_autoadd_score_explanation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_score_explanation(raw);
+  }
+
+  @protected
+  SearchCursor dco_decode_box_autoadd_search_cursor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_search_cursor(raw);
+  }
+
+  @protected
+  SearchEngineError dco_decode_box_autoadd_search_engine_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_search_engine_error(raw);
+  }
+
+  @protected
+  SearchQuery dco_decode_box_autoadd_search_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_search_query(raw);
+  }
+
+  @protected
+  SnippetOptions dco_decode_box_autoadd_snippet_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_snippet_options(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  CategoryCount dco_decode_category_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CategoryCount(
+      category: dco_decode_String(arr[0]),
+      count: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  ClauseOccur dco_decode_clause_occur(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ClauseOccur.values[raw as int];
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FieldFilter dco_decode_field_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FieldFilter(
+      field: dco_decode_filter_field(arr[0]),
+      values: dco_decode_list_String(arr[1]),
+      exclude: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  FilterField dco_decode_filter_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FilterField.values[raw as int];
+  }
+
+  @protected
+  GematriaMethod dco_decode_gematria_method(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return GematriaMethod.values[raw as int];
+  }
+
+  @protected
+  GematriaOptions dco_decode_gematria_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GematriaOptions(
+      method: dco_decode_gematria_method(arr[0]),
+      minWords: dco_decode_u_32(arr[1]),
+      maxWords: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  Highlight dco_decode_highlight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return Highlight(
+      byteStart: dco_decode_u_32(arr[0]),
+      byteEnd: dco_decode_u_32(arr[1]),
+      charStart: dco_decode_u_32(arr[2]),
+      charEnd: dco_decode_u_32(arr[3]),
+      term: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  HighlightTags dco_decode_highlight_tags(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return HighlightTags(
+      open: dco_decode_String(arr[0]),
+      close: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   List<String> dco_decode_list_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<BookCount> dco_decode_list_book_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_book_count).toList();
+  }
+
+  @protected
+  List<CategoryCount> dco_decode_list_category_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_category_count).toList();
+  }
+
+  @protected
+  List<FieldFilter> dco_decode_list_field_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_field_filter).toList();
+  }
+
+  @protected
+  List<Highlight> dco_decode_list_highlight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_highlight).toList();
+  }
+
+  @protected
+  List<NeighborSegment> dco_decode_list_neighbor_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_neighbor_segment).toList();
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as Uint8List;
   }
 
+  @protected
+  List<QueryClause> dco_decode_list_query_clause(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_query_clause).toList();
+  }
+
+  @protected
+  List<QueryExpansion> dco_decode_list_query_expansion(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_query_expansion).toList();
+  }
+
+  @protected
+  List<QueryNode> dco_decode_list_query_node(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_query_node).toList();
+  }
+
+  @protected
+  List<ScoreExplanation> dco_decode_list_score_explanation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_score_explanation).toList();
+  }
+
   @protected
   List<SearchResult> dco_decode_list_search_result(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -500,361 +1324,1789 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  SearchResult dco_decode_search_result(dynamic raw) {
+  List<SegmentHighlights> dco_decode_list_segment_highlights(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_segment_highlights).toList();
+  }
+
+  @protected
+  MatchOptions dco_decode_match_options(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return SearchResult(
-      title: dco_decode_String(arr[0]),
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MatchOptions(
+      prefixInsensitive: dco_decode_bool(arr[0]),
+      spellingInsensitive: dco_decode_bool(arr[1]),
+      byRoot: dco_decode_bool(arr[2]),
+      divineNames: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  NeighborSegment dco_decode_neighbor_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return NeighborSegment(
+      segment: dco_decode_u_64(arr[0]),
       text: dco_decode_String(arr[1]),
-      id: dco_decode_u_64(arr[2]),
-      segment: dco_decode_u_64(arr[3]),
-      isPdf: dco_decode_bool(arr[4]),
-      filePath: dco_decode_String(arr[5]),
     );
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
+  String? dco_decode_opt_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+    return raw == null ? null : dco_decode_String(raw);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
+  HighlightTags? dco_decode_opt_box_autoadd_highlight_tags(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+    return raw == null ? null : dco_decode_box_autoadd_highlight_tags(raw);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
+  Proximity? dco_decode_opt_box_autoadd_proximity(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+    return raw == null ? null : dco_decode_box_autoadd_proximity(raw);
+  }
+
+  @protected
+  QueryNode? dco_decode_opt_box_autoadd_query_node(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_query_node(raw);
+  }
+
+  @protected
+  ScoreExplanation? dco_decode_opt_box_autoadd_score_explanation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_score_explanation(raw);
+  }
+
+  @protected
+  SearchCursor? dco_decode_opt_box_autoadd_search_cursor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_search_cursor(raw);
+  }
+
+  @protected
+  SearchEngineError? dco_decode_opt_box_autoadd_search_engine_error(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_search_engine_error(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  Proximity dco_decode_proximity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Proximity(
+      maxDistance: dco_decode_u_32(arr[0]),
+      ordered: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  QueryClause dco_decode_query_clause(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return QueryClause(
+      words: dco_decode_list_String(arr[0]),
+      occur: dco_decode_clause_occur(arr[1]),
+      fuzziness: dco_decode_u_8(arr[2]),
+      proximity: dco_decode_opt_box_autoadd_proximity(arr[3]),
+    );
+  }
+
+  @protected
+  QueryExpansion dco_decode_query_expansion(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QueryExpansion(
+      original: dco_decode_String(arr[0]),
+      alternatives: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  QueryExplanation dco_decode_query_explanation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return QueryExplanation(
+      parsedQuery: dco_decode_String(arr[0]),
+      expansions: dco_decode_list_query_expansion(arr[1]),
+      root: dco_decode_opt_box_autoadd_query_node(arr[2]),
+      error: dco_decode_opt_box_autoadd_search_engine_error(arr[3]),
+    );
+  }
+
+  @protected
+  QueryNode dco_decode_query_node(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return QueryNode(
+      kind: dco_decode_query_node_kind(arr[0]),
+      occur: dco_decode_clause_occur(arr[1]),
+      field: dco_decode_String(arr[2]),
+      text: dco_decode_String(arr[3]),
+      terms: dco_decode_list_String(arr[4]),
+      distance: dco_decode_u_32(arr[5]),
+      ordered: dco_decode_bool(arr[6]),
+      boost: dco_decode_f_32(arr[7]),
+      fuzzyExpansions: dco_decode_list_String(arr[8]),
+      children: dco_decode_list_query_node(arr[9]),
+    );
+  }
+
+  @protected
+  QueryNodeKind dco_decode_query_node_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QueryNodeKind.values[raw as int];
+  }
+
+  @protected
+  ScoreExplanation dco_decode_score_explanation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ScoreExplanation(
+      value: dco_decode_f_32(arr[0]),
+      description: dco_decode_String(arr[1]),
+      details: dco_decode_list_score_explanation(arr[2]),
+      context: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  SearchCursor dco_decode_search_cursor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return SearchCursor(
+      score: dco_decode_f_32(arr[0]),
+      id: dco_decode_u_64(arr[1]),
+      title: dco_decode_String(arr[2]),
+      segment: dco_decode_u_64(arr[3]),
+      segmentOrd: dco_decode_u_32(arr[4]),
+      docId: dco_decode_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  SearchEngineError dco_decode_search_engine_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SearchEngineError(
+      kind: dco_decode_search_engine_error_kind(arr[0]),
+      message: dco_decode_String(arr[1]),
+      position: dco_decode_opt_box_autoadd_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  SearchEngineErrorKind dco_decode_search_engine_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SearchEngineErrorKind.values[raw as int];
+  }
+
+  @protected
+  SearchQuery dco_decode_search_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SearchQuery(
+      clauses: dco_decode_list_query_clause(arr[0]),
+      filters: dco_decode_list_field_filter(arr[1]),
+    );
+  }
+
+  @protected
+  SearchResponse dco_decode_search_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SearchResponse(
+      hits: dco_decode_list_search_result(arr[0]),
+      totalCount: dco_decode_u_64(arr[1]),
+      perBookCounts: dco_decode_list_book_count(arr[2]),
+      perCategoryCounts: dco_decode_list_category_count(arr[3]),
+    );
+  }
+
+  @protected
+  SearchResult dco_decode_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return SearchResult(
+      title: dco_decode_String(arr[0]),
+      text: dco_decode_String(arr[1]),
+      highlights: dco_decode_list_highlight(arr[2]),
+      html: dco_decode_opt_String(arr[3]),
+      id: dco_decode_u_64(arr[4]),
+      segment: dco_decode_u_64(arr[5]),
+      isPdf: dco_decode_bool(arr[6]),
+      filePath: dco_decode_String(arr[7]),
+      cursor: dco_decode_search_cursor(arr[8]),
+      preceding: dco_decode_list_neighbor_segment(arr[9]),
+      following: dco_decode_list_neighbor_segment(arr[10]),
+    );
+  }
+
+  @protected
+  SegmentHighlights dco_decode_segment_highlights(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SegmentHighlights(
+      segment: dco_decode_u_64(arr[0]),
+      highlights: dco_decode_list_highlight(arr[1]),
+    );
+  }
+
+  @protected
+  SnippetBoundary dco_decode_snippet_boundary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SnippetBoundary.values[raw as int];
+  }
+
+  @protected
+  SnippetOptions dco_decode_snippet_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SnippetOptions(
+      maxChars: dco_decode_u_32(arr[0]),
+      fragments: dco_decode_u_32(arr[1]),
+      separator: dco_decode_String(arr[2]),
+      boundary: dco_decode_snippet_boundary(arr[3]),
+      htmlTags: dco_decode_opt_box_autoadd_highlight_tags(arr[4]),
+    );
+  }
+
+  @protected
+  SortOrder dco_decode_sort_order(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SortOrder.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  BoxQuery
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return BoxQueryImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  SearchEngine
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SearchEngineImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  SearchEngine
+      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SearchEngineImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  Index
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return IndexImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  SearchEngine
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SearchEngineImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  BoxQuery
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return BoxQueryImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  Index
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return IndexImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  SearchEngine
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SearchEngineImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RustStreamSink<List<SearchResult>>
+      sse_decode_StreamSink_list_search_result_Sse(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  BookCount sse_decode_book_count(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_count = sse_decode_u_64(deserializer);
+    return BookCount(title: var_title, count: var_count);
+  }
+
+  @protected
+  BookFilter sse_decode_book_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_allBooks = sse_decode_bool(deserializer);
+    var var_includeTitles = sse_decode_list_String(deserializer);
+    var var_includeCategories = sse_decode_list_String(deserializer);
+    var var_excludeTitles = sse_decode_list_String(deserializer);
+    var var_excludeCategories = sse_decode_list_String(deserializer);
+    return BookFilter(
+        allBooks: var_allBooks,
+        includeTitles: var_includeTitles,
+        includeCategories: var_includeCategories,
+        excludeTitles: var_excludeTitles,
+        excludeCategories: var_excludeCategories);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  BookFilter sse_decode_box_autoadd_book_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_book_filter(deserializer));
+  }
+
+  @protected
+  GematriaOptions sse_decode_box_autoadd_gematria_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_gematria_options(deserializer));
+  }
+
+  @protected
+  HighlightTags sse_decode_box_autoadd_highlight_tags(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_highlight_tags(deserializer));
+  }
+
+  @protected
+  MatchOptions sse_decode_box_autoadd_match_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_match_options(deserializer));
+  }
+
+  @protected
+  Proximity sse_decode_box_autoadd_proximity(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_proximity(deserializer));
+  }
+
+  @protected
+  QueryNode sse_decode_box_autoadd_query_node(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_query_node(deserializer));
+  }
+
+  @protected
+  ScoreExplanation sse_decode_box_autoadd_score_explanation(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_score_explanation(deserializer));
+  }
+
+  @protected
+  SearchCursor sse_decode_box_autoadd_search_cursor(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_search_cursor(deserializer));
+  }
+
+  @protected
+  SearchEngineError sse_decode_box_autoadd_search_engine_error(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_search_engine_error(deserializer));
+  }
+
+  @protected
+  SearchQuery sse_decode_box_autoadd_search_query(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_search_query(deserializer));
+  }
+
+  @protected
+  SnippetOptions sse_decode_box_autoadd_snippet_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_snippet_options(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  CategoryCount sse_decode_category_count(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_category = sse_decode_String(deserializer);
+    var var_count = sse_decode_u_64(deserializer);
+    return CategoryCount(category: var_category, count: var_count);
+  }
+
+  @protected
+  ClauseOccur sse_decode_clause_occur(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ClauseOccur.values[inner];
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  FieldFilter sse_decode_field_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_filter_field(deserializer);
+    var var_values = sse_decode_list_String(deserializer);
+    var var_exclude = sse_decode_bool(deserializer);
+    return FieldFilter(
+        field: var_field, values: var_values, exclude: var_exclude);
+  }
+
+  @protected
+  FilterField sse_decode_filter_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FilterField.values[inner];
+  }
+
+  @protected
+  GematriaMethod sse_decode_gematria_method(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return GematriaMethod.values[inner];
+  }
+
+  @protected
+  GematriaOptions sse_decode_gematria_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_method = sse_decode_gematria_method(deserializer);
+    var var_minWords = sse_decode_u_32(deserializer);
+    var var_maxWords = sse_decode_u_32(deserializer);
+    return GematriaOptions(
+        method: var_method, minWords: var_minWords, maxWords: var_maxWords);
+  }
+
+  @protected
+  Highlight sse_decode_highlight(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_byteStart = sse_decode_u_32(deserializer);
+    var var_byteEnd = sse_decode_u_32(deserializer);
+    var var_charStart = sse_decode_u_32(deserializer);
+    var var_charEnd = sse_decode_u_32(deserializer);
+    var var_term = sse_decode_String(deserializer);
+    return Highlight(
+        byteStart: var_byteStart,
+        byteEnd: var_byteEnd,
+        charStart: var_charStart,
+        charEnd: var_charEnd,
+        term: var_term);
+  }
+
+  @protected
+  HighlightTags sse_decode_highlight_tags(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_open = sse_decode_String(deserializer);
+    var var_close = sse_decode_String(deserializer);
+    return HighlightTags(open: var_open, close: var_close);
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BookCount> sse_decode_list_book_count(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BookCount>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_book_count(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CategoryCount> sse_decode_list_category_count(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CategoryCount>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_category_count(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FieldFilter> sse_decode_list_field_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FieldFilter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_field_filter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Highlight> sse_decode_list_highlight(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Highlight>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_highlight(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NeighborSegment> sse_decode_list_neighbor_segment(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NeighborSegment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_neighbor_segment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<QueryClause> sse_decode_list_query_clause(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <QueryClause>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_query_clause(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<QueryExpansion> sse_decode_list_query_expansion(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <QueryExpansion>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_query_expansion(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<QueryNode> sse_decode_list_query_node(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <QueryNode>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_query_node(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ScoreExplanation> sse_decode_list_score_explanation(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ScoreExplanation>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_score_explanation(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SearchResult> sse_decode_list_search_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SearchResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_search_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SegmentHighlights> sse_decode_list_segment_highlights(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SegmentHighlights>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_segment_highlights(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MatchOptions sse_decode_match_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_prefixInsensitive = sse_decode_bool(deserializer);
+    var var_spellingInsensitive = sse_decode_bool(deserializer);
+    var var_byRoot = sse_decode_bool(deserializer);
+    var var_divineNames = sse_decode_bool(deserializer);
+    return MatchOptions(
+        prefixInsensitive: var_prefixInsensitive,
+        spellingInsensitive: var_spellingInsensitive,
+        byRoot: var_byRoot,
+        divineNames: var_divineNames);
+  }
+
+  @protected
+  NeighborSegment sse_decode_neighbor_segment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_segment = sse_decode_u_64(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    return NeighborSegment(segment: var_segment, text: var_text);
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    }
+  }
+
+  @protected
+  HighlightTags? sse_decode_opt_box_autoadd_highlight_tags(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_highlight_tags(deserializer));
+    }
+  }
+
+  @protected
+  Proximity? sse_decode_opt_box_autoadd_proximity(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_proximity(deserializer));
+    }
+  }
+
+  @protected
+  QueryNode? sse_decode_opt_box_autoadd_query_node(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_query_node(deserializer));
+    }
+  }
+
+  @protected
+  ScoreExplanation? sse_decode_opt_box_autoadd_score_explanation(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_score_explanation(deserializer));
+    }
+  }
+
+  @protected
+  SearchCursor? sse_decode_opt_box_autoadd_search_cursor(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_search_cursor(deserializer));
+    }
+  }
+
+  @protected
+  SearchEngineError? sse_decode_opt_box_autoadd_search_engine_error(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_search_engine_error(deserializer));
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    }
+  }
+
+  @protected
+  Proximity sse_decode_proximity(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxDistance = sse_decode_u_32(deserializer);
+    var var_ordered = sse_decode_bool(deserializer);
+    return Proximity(maxDistance: var_maxDistance, ordered: var_ordered);
+  }
+
+  @protected
+  QueryClause sse_decode_query_clause(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_words = sse_decode_list_String(deserializer);
+    var var_occur = sse_decode_clause_occur(deserializer);
+    var var_fuzziness = sse_decode_u_8(deserializer);
+    var var_proximity = sse_decode_opt_box_autoadd_proximity(deserializer);
+    return QueryClause(
+        words: var_words,
+        occur: var_occur,
+        fuzziness: var_fuzziness,
+        proximity: var_proximity);
+  }
+
+  @protected
+  QueryExpansion sse_decode_query_expansion(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_original = sse_decode_String(deserializer);
+    var var_alternatives = sse_decode_list_String(deserializer);
+    return QueryExpansion(
+        original: var_original, alternatives: var_alternatives);
+  }
+
+  @protected
+  QueryExplanation sse_decode_query_explanation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_parsedQuery = sse_decode_String(deserializer);
+    var var_expansions = sse_decode_list_query_expansion(deserializer);
+    var var_root = sse_decode_opt_box_autoadd_query_node(deserializer);
+    var var_error = sse_decode_opt_box_autoadd_search_engine_error(
+        deserializer);
+    return QueryExplanation(
+        parsedQuery: var_parsedQuery,
+        expansions: var_expansions,
+        root: var_root,
+        error: var_error);
+  }
+
+  @protected
+  QueryNode sse_decode_query_node(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_query_node_kind(deserializer);
+    var var_occur = sse_decode_clause_occur(deserializer);
+    var var_field = sse_decode_String(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_terms = sse_decode_list_String(deserializer);
+    var var_distance = sse_decode_u_32(deserializer);
+    var var_ordered = sse_decode_bool(deserializer);
+    var var_boost = sse_decode_f_32(deserializer);
+    var var_fuzzyExpansions = sse_decode_list_String(deserializer);
+    var var_children = sse_decode_list_query_node(deserializer);
+    return QueryNode(
+        kind: var_kind,
+        occur: var_occur,
+        field: var_field,
+        text: var_text,
+        terms: var_terms,
+        distance: var_distance,
+        ordered: var_ordered,
+        boost: var_boost,
+        fuzzyExpansions: var_fuzzyExpansions,
+        children: var_children);
+  }
+
+  @protected
+  QueryNodeKind sse_decode_query_node_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return QueryNodeKind.values[inner];
+  }
+
+  @protected
+  ScoreExplanation sse_decode_score_explanation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_value = sse_decode_f_32(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_details = sse_decode_list_score_explanation(deserializer);
+    var var_context = sse_decode_list_String(deserializer);
+    return ScoreExplanation(
+        value: var_value,
+        description: var_description,
+        details: var_details,
+        context: var_context);
+  }
+
+  @protected
+  SearchCursor sse_decode_search_cursor(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_score = sse_decode_f_32(deserializer);
+    var var_id = sse_decode_u_64(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_segment = sse_decode_u_64(deserializer);
+    var var_segmentOrd = sse_decode_u_32(deserializer);
+    var var_docId = sse_decode_u_32(deserializer);
+    return SearchCursor(
+        score: var_score,
+        id: var_id,
+        title: var_title,
+        segment: var_segment,
+        segmentOrd: var_segmentOrd,
+        docId: var_docId);
+  }
+
+  @protected
+  SearchEngineError sse_decode_search_engine_error(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_search_engine_error_kind(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_position = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return SearchEngineError(
+        kind: var_kind, message: var_message, position: var_position);
+  }
+
+  @protected
+  SearchEngineErrorKind sse_decode_search_engine_error_kind(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SearchEngineErrorKind.values[inner];
+  }
+
+  @protected
+  SearchQuery sse_decode_search_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_clauses = sse_decode_list_query_clause(deserializer);
+    var var_filters = sse_decode_list_field_filter(deserializer);
+    return SearchQuery(clauses: var_clauses, filters: var_filters);
+  }
+
+  @protected
+  SearchResponse sse_decode_search_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_hits = sse_decode_list_search_result(deserializer);
+    var var_totalCount = sse_decode_u_64(deserializer);
+    var var_perBookCounts = sse_decode_list_book_count(deserializer);
+    var var_perCategoryCounts = sse_decode_list_category_count(deserializer);
+    return SearchResponse(
+        hits: var_hits,
+        totalCount: var_totalCount,
+        perBookCounts: var_perBookCounts,
+        perCategoryCounts: var_perCategoryCounts);
+  }
+
+  @protected
+  SearchResult sse_decode_search_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_highlights = sse_decode_list_highlight(deserializer);
+    var var_html = sse_decode_opt_String(deserializer);
+    var var_id = sse_decode_u_64(deserializer);
+    var var_segment = sse_decode_u_64(deserializer);
+    var var_isPdf = sse_decode_bool(deserializer);
+    var var_filePath = sse_decode_String(deserializer);
+    var var_cursor = sse_decode_search_cursor(deserializer);
+    var var_preceding = sse_decode_list_neighbor_segment(deserializer);
+    var var_following = sse_decode_list_neighbor_segment(deserializer);
+    return SearchResult(
+        title: var_title,
+        text: var_text,
+        highlights: var_highlights,
+        html: var_html,
+        id: var_id,
+        segment: var_segment,
+        isPdf: var_isPdf,
+        filePath: var_filePath,
+        cursor: var_cursor,
+        preceding: var_preceding,
+        following: var_following);
+  }
+
+  @protected
+  SegmentHighlights sse_decode_segment_highlights(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_segment = sse_decode_u_64(deserializer);
+    var var_highlights = sse_decode_list_highlight(deserializer);
+    return SegmentHighlights(segment: var_segment, highlights: var_highlights);
+  }
+
+  @protected
+  SnippetBoundary sse_decode_snippet_boundary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SnippetBoundary.values[inner];
+  }
+
+  @protected
+  SnippetOptions sse_decode_snippet_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxChars = sse_decode_u_32(deserializer);
+    var var_fragments = sse_decode_u_32(deserializer);
+    var var_separator = sse_decode_String(deserializer);
+    var var_boundary = sse_decode_snippet_boundary(deserializer);
+    var var_htmlTags = sse_decode_opt_box_autoadd_highlight_tags(deserializer);
+    return SnippetOptions(
+        maxChars: var_maxChars,
+        fragments: var_fragments,
+        separator: var_separator,
+        boundary: var_boundary,
+        htmlTags: var_htmlTags);
+  }
+
+  @protected
+  SortOrder sse_decode_sort_order(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SortOrder.values[inner];
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery(
+          BoxQuery self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as BoxQueryImpl).frbInternalSseEncode(move: true), serializer);
+  }
+
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+          SearchEngine self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as SearchEngineImpl).frbInternalSseEncode(move: true),
+        serializer);
+  }
+
+  @protected
+  void
+      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+          SearchEngine self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as SearchEngineImpl).frbInternalSseEncode(move: false),
+        serializer);
+  }
+
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex(
+          Index self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as IndexImpl).frbInternalSseEncode(move: false), serializer);
+  }
+
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+          SearchEngine self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as SearchEngineImpl).frbInternalSseEncode(move: false),
+        serializer);
+  }
+
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery(
+          BoxQuery self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as BoxQueryImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex(
+          Index self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as IndexImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+          SearchEngine self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as SearchEngineImpl).frbInternalSseEncode(move: null),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_list_search_result_Sse(
+      RustStreamSink<List<SearchResult>> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_book_count(BookCount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.title, serializer);
+    sse_encode_u_64(self.count, serializer);
+  }
+
+  @protected
+  void sse_encode_book_filter(BookFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.allBooks, serializer);
+    sse_encode_list_String(self.includeTitles, serializer);
+    sse_encode_list_String(self.includeCategories, serializer);
+    sse_encode_list_String(self.excludeTitles, serializer);
+    sse_encode_list_String(self.excludeCategories, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_book_filter(
+      BookFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_book_filter(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_gematria_options(
+      GematriaOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_gematria_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_highlight_tags(
+      HighlightTags self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_highlight_tags(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_match_options(
+      MatchOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_match_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_proximity(
+      Proximity self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_proximity(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_query_node(
+      QueryNode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_query_node(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_score_explanation(
+      ScoreExplanation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_score_explanation(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_search_cursor(
+      SearchCursor self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_search_cursor(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_search_engine_error(
+      SearchEngineError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_search_engine_error(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_search_query(
+      SearchQuery self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_search_query(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_snippet_options(
+      SnippetOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_snippet_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_category_count(CategoryCount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.category, serializer);
+    sse_encode_u_64(self.count, serializer);
+  }
+
+  @protected
+  void sse_encode_clause_occur(ClauseOccur self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
+  }
+
+  @protected
+  void sse_encode_field_filter(FieldFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_filter_field(self.field, serializer);
+    sse_encode_list_String(self.values, serializer);
+    sse_encode_bool(self.exclude, serializer);
+  }
+
+  @protected
+  void sse_encode_filter_field(FilterField self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_gematria_method(
+      GematriaMethod self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_gematria_options(
+      GematriaOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_gematria_method(self.method, serializer);
+    sse_encode_u_32(self.minWords, serializer);
+    sse_encode_u_32(self.maxWords, serializer);
+  }
+
+  @protected
+  void sse_encode_highlight(Highlight self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.byteStart, serializer);
+    sse_encode_u_32(self.byteEnd, serializer);
+    sse_encode_u_32(self.charStart, serializer);
+    sse_encode_u_32(self.charEnd, serializer);
+    sse_encode_String(self.term, serializer);
+  }
+
+  @protected
+  void sse_encode_highlight_tags(HighlightTags self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.open, serializer);
+    sse_encode_String(self.close, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_book_count(
+      List<BookCount> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_book_count(item, serializer);
+    }
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_list_category_count(
+      List<CategoryCount> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_category_count(item, serializer);
+    }
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_list_field_filter(
+      List<FieldFilter> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_field_filter(item, serializer);
+    }
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_list_highlight(
+      List<Highlight> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_highlight(item, serializer);
+    }
   }
 
   @protected
-  BoxQuery
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery(
-          SseDeserializer deserializer) {
+  void sse_encode_list_neighbor_segment(
+      List<NeighborSegment> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return BoxQueryImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_neighbor_segment(item, serializer);
+    }
   }
 
   @protected
-  SearchEngine
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
-          SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+      Uint8List self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return SearchEngineImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  SearchEngine
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
-          SseDeserializer deserializer) {
+  void sse_encode_list_query_clause(
+      List<QueryClause> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return SearchEngineImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_query_clause(item, serializer);
+    }
   }
 
   @protected
-  Index
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex(
-          SseDeserializer deserializer) {
+  void sse_encode_list_query_expansion(
+      List<QueryExpansion> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return IndexImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_query_expansion(item, serializer);
+    }
   }
 
   @protected
-  BoxQuery
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery(
-          SseDeserializer deserializer) {
+  void sse_encode_list_query_node(
+      List<QueryNode> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return BoxQueryImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_query_node(item, serializer);
+    }
   }
 
   @protected
-  Index
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex(
-          SseDeserializer deserializer) {
+  void sse_encode_list_score_explanation(
+      List<ScoreExplanation> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return IndexImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_score_explanation(item, serializer);
+    }
   }
 
   @protected
-  SearchEngine
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
-          SseDeserializer deserializer) {
+  void sse_encode_list_search_result(
+      List<SearchResult> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return SearchEngineImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_search_result(item, serializer);
+    }
   }
 
   @protected
-  RustStreamSink<List<SearchResult>>
-      sse_decode_StreamSink_list_search_result_Sse(
-          SseDeserializer deserializer) {
+  void sse_encode_list_segment_highlights(
+      List<SegmentHighlights> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_segment_highlights(item, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_match_options(MatchOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_bool(self.prefixInsensitive, serializer);
+    sse_encode_bool(self.spellingInsensitive, serializer);
+    sse_encode_bool(self.byRoot, serializer);
+    sse_encode_bool(self.divineNames, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_neighbor_segment(
+      NeighborSegment self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_u_64(self.segment, serializer);
+    sse_encode_String(self.text, serializer);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_highlight_tags(
+      HighlightTags? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_highlight_tags(self, serializer);
+    }
   }
 
   @protected
-  List<SearchResult> sse_decode_list_search_result(
-      SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_proximity(
+      Proximity? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SearchResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_search_result(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_proximity(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  SearchResult sse_decode_search_result(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_query_node(
+      QueryNode? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_title = sse_decode_String(deserializer);
-    var var_text = sse_decode_String(deserializer);
-    var var_id = sse_decode_u_64(deserializer);
-    var var_segment = sse_decode_u_64(deserializer);
-    var var_isPdf = sse_decode_bool(deserializer);
-    var var_filePath = sse_decode_String(deserializer);
-    return SearchResult(
-        title: var_title,
-        text: var_text,
-        id: var_id,
-        segment: var_segment,
-        isPdf: var_isPdf,
-        filePath: var_filePath);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_query_node(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_score_explanation(
+      ScoreExplanation? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_score_explanation(self, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_search_cursor(
+      SearchCursor? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_search_cursor(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_search_engine_error(
+      SearchEngineError? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_search_engine_error(self, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_proximity(Proximity self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_u_32(self.maxDistance, serializer);
+    sse_encode_bool(self.ordered, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_query_clause(QueryClause self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_list_String(self.words, serializer);
+    sse_encode_clause_occur(self.occur, serializer);
+    sse_encode_u_8(self.fuzziness, serializer);
+    sse_encode_opt_box_autoadd_proximity(self.proximity, serializer);
   }
 
   @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
+  void sse_encode_query_expansion(
+      QueryExpansion self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_String(self.original, serializer);
+    sse_encode_list_String(self.alternatives, serializer);
   }
 
   @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery(
-          BoxQuery self, SseSerializer serializer) {
+  void sse_encode_query_explanation(
+      QueryExplanation self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as BoxQueryImpl).frbInternalSseEncode(move: true), serializer);
+    sse_encode_String(self.parsedQuery, serializer);
+    sse_encode_list_query_expansion(self.expansions, serializer);
+    sse_encode_opt_box_autoadd_query_node(self.root, serializer);
+    sse_encode_opt_box_autoadd_search_engine_error(self.error, serializer);
   }
 
   @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
-          SearchEngine self, SseSerializer serializer) {
+  void sse_encode_query_node(QueryNode self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as SearchEngineImpl).frbInternalSseEncode(move: true),
-        serializer);
+    sse_encode_query_node_kind(self.kind, serializer);
+    sse_encode_clause_occur(self.occur, serializer);
+    sse_encode_String(self.field, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_list_String(self.terms, serializer);
+    sse_encode_u_32(self.distance, serializer);
+    sse_encode_bool(self.ordered, serializer);
+    sse_encode_f_32(self.boost, serializer);
+    sse_encode_list_String(self.fuzzyExpansions, serializer);
+    sse_encode_list_query_node(self.children, serializer);
   }
 
   @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
-          SearchEngine self, SseSerializer serializer) {
+  void sse_encode_query_node_kind(
+      QueryNodeKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as SearchEngineImpl).frbInternalSseEncode(move: false),
-        serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex(
-          Index self, SseSerializer serializer) {
+  void sse_encode_score_explanation(
+      ScoreExplanation self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as IndexImpl).frbInternalSseEncode(move: false), serializer);
+    sse_encode_f_32(self.value, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_list_score_explanation(self.details, serializer);
+    sse_encode_list_String(self.context, serializer);
   }
 
   @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery(
-          BoxQuery self, SseSerializer serializer) {
+  void sse_encode_search_cursor(SearchCursor self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as BoxQueryImpl).frbInternalSseEncode(move: null), serializer);
+    sse_encode_f_32(self.score, serializer);
+    sse_encode_u_64(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_u_64(self.segment, serializer);
+    sse_encode_u_32(self.segmentOrd, serializer);
+    sse_encode_u_32(self.docId, serializer);
   }
 
   @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex(
-          Index self, SseSerializer serializer) {
+  void sse_encode_search_engine_error(
+      SearchEngineError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as IndexImpl).frbInternalSseEncode(move: null), serializer);
+    sse_encode_search_engine_error_kind(self.kind, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.position, serializer);
   }
 
   @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
-          SearchEngine self, SseSerializer serializer) {
+  void sse_encode_search_engine_error_kind(
+      SearchEngineErrorKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as SearchEngineImpl).frbInternalSseEncode(move: null),
-        serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_StreamSink_list_search_result_Sse(
-      RustStreamSink<List<SearchResult>> self, SseSerializer serializer) {
+  void sse_encode_search_query(SearchQuery self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-        self.setupAndSerialize(
-            codec: SseCodec(
-          decodeSuccessData: sse_decode_list_search_result,
-          decodeErrorData: sse_decode_AnyhowException,
-        )),
-        serializer);
+    sse_encode_list_query_clause(self.clauses, serializer);
+    sse_encode_list_field_filter(self.filters, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_search_response(
+      SearchResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_list_search_result(self.hits, serializer);
+    sse_encode_u_64(self.totalCount, serializer);
+    sse_encode_list_book_count(self.perBookCounts, serializer);
+    sse_encode_list_category_count(self.perCategoryCounts, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_search_result(SearchResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_list_highlight(self.highlights, serializer);
+    sse_encode_opt_String(self.html, serializer);
+    sse_encode_u_64(self.id, serializer);
+    sse_encode_u_64(self.segment, serializer);
+    sse_encode_bool(self.isPdf, serializer);
+    sse_encode_String(self.filePath, serializer);
+    sse_encode_search_cursor(self.cursor, serializer);
+    sse_encode_list_neighbor_segment(self.preceding, serializer);
+    sse_encode_list_neighbor_segment(self.following, serializer);
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_segment_highlights(
+      SegmentHighlights self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
+    sse_encode_u_64(self.segment, serializer);
+    sse_encode_list_highlight(self.highlights, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
+  void sse_encode_snippet_boundary(
+      SnippetBoundary self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_search_result(
-      List<SearchResult> self, SseSerializer serializer) {
+  void sse_encode_snippet_options(
+      SnippetOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_search_result(item, serializer);
-    }
+    sse_encode_u_32(self.maxChars, serializer);
+    sse_encode_u_32(self.fragments, serializer);
+    sse_encode_String(self.separator, serializer);
+    sse_encode_snippet_boundary(self.boundary, serializer);
+    sse_encode_opt_box_autoadd_highlight_tags(self.htmlTags, serializer);
   }
 
   @protected
-  void sse_encode_search_result(SearchResult self, SseSerializer serializer) {
+  void sse_encode_sort_order(SortOrder self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.title, serializer);
-    sse_encode_String(self.text, serializer);
-    sse_encode_u_64(self.id, serializer);
-    sse_encode_u_64(self.segment, serializer);
-    sse_encode_bool(self.isPdf, serializer);
-    sse_encode_String(self.filePath, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
@@ -885,12 +3137,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putBigUint64(self);
   }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
 }
 
 @sealed
@@ -973,19 +3219,149 @@ class SearchEngineImpl extends RustOpaque implements SearchEngine {
         that: this,
       );
 
-  Future<List<SearchResult>> search(
+  /// Describes the query `create_search_query` builds from `search_term`
+  /// with the same arguments, for showing the user how it was read: which
+  /// words are required or excluded, which are looked up as phrases, which
+  /// indexed words fuzzy words match and, for an invalid query, why.
+  Future<QueryExplanation> explainQuery(
+          {required String searchTerm,
+          required bool fuzzy,
+          Proximity? proximity,
+          required MatchOptions options}) =>
+      RustLib.instance.api.crateApiSearchEngineSearchEngineExplainQuery(
+          that: this,
+          searchTerm: searchTerm,
+          fuzzy: fuzzy,
+          proximity: proximity,
+          options: options);
+
+  /// Explains the score of the result with id `id` of the search
+  /// `create_search_query` builds from the other arguments, for finding
+  /// out why it ranks where it does. `None` when it is not a result.
+  Future<ScoreExplanation?> explainScore(
+          {required String query,
+          required BookFilter books,
+          required bool fuzzy,
+          Proximity? proximity,
+          required MatchOptions options,
+          required BigInt id}) =>
+      RustLib.instance.api.crateApiSearchEngineSearchEngineExplainScore(
+          that: this,
+          query: query,
+          books: books,
+          fuzzy: fuzzy,
+          proximity: proximity,
+          options: options,
+          id: id);
+
+  /// Finds every match of `query`, searched for like `search` does with
+  /// the same arguments, in the segments of the book titled `title` from
+  /// `segment_start` up to `segment_end`, excluded, for highlighting them
+  /// all when the book is opened. Segments the query does not match are
+  /// left out, and in the others matches are found the way snippets
+  /// highlight them, fuzzy matches and other forms of the words included.
+  Future<List<SegmentHighlights>> highlightInDocument(
+          {required String query,
+          required String title,
+          required BigInt segmentStart,
+          required BigInt segmentEnd,
+          required bool fuzzy,
+          Proximity? proximity,
+          required MatchOptions options}) =>
+      RustLib.instance.api.crateApiSearchEngineSearchEngineHighlightInDocument(
+          that: this,
+          query: query,
+          title: title,
+          segmentStart: segmentStart,
+          segmentEnd: segmentEnd,
+          fuzzy: fuzzy,
+          proximity: proximity,
+          options: options);
+
+  /// Searches `books` for `query` and returns `limit` results in `order`,
+  /// after skipping `offset` results, together with the number of results
+  /// overall, per book and per category. With `after`, the cursor of the
+  /// last result of a page, the results following it are returned, so the
+  /// next page costs no more than the first. Each result comes with the
+  /// `context_segments` segments before and after it in its book.
+  Future<SearchResponse> search(
           {required String query,
-          required List<String> books,
+          required BookFilter books,
           required int limit,
-          required bool fuzzy}) =>
-      RustLib.instance.api.crateApiSearchEngineSearchEngineSearch(
-          that: this, query: query, books: books, limit: limit, fuzzy: fuzzy);
-
+          required bool fuzzy,
+          Proximity? proximity,
+          required MatchOptions options,
+          required SnippetOptions snippetOptions,
+          required int contextSegments,
+          required SortOrder order,
+          required int offset,
+          SearchCursor? after}) => RustLib.instance.api
+      .crateApiSearchEngineSearchEngineSearch(
+          that: this,
+          query: query,
+          books: books,
+          limit: limit,
+          fuzzy: fuzzy,
+          proximity: proximity,
+          options: options,
+          snippetOptions: snippetOptions,
+          contextSegments: contextSegments,
+          order: order,
+          offset: offset,
+          after: after);
+
+  /// Finds words, and runs of consecutive words, whose gematria is
+  /// `value`, with the matching words highlighted. Results come in
+  /// document order.
+  Future<SearchResponse> searchGematria(
+          {required int value,
+          required BookFilter books,
+          required int limit,
+          required GematriaOptions options,
+          required SnippetOptions snippetOptions}) =>
+      RustLib.instance.api.crateApiSearchEngineSearchEngineSearchGematria(
+          that: this,
+          value: value,
+          books: books,
+          limit: limit,
+          options: options,
+          snippetOptions: snippetOptions);
+
+  /// Searches like `search`, sending the results found so far to `sink`
+  /// after each result, with snippets made with the default options.
   Stream<List<SearchResult>> searchStream(
           {required String query,
-          required List<String> books,
+          required BookFilter books,
           required int limit,
-          required bool fuzzy}) =>
+          required bool fuzzy,
+          Proximity? proximity,
+          required MatchOptions options,
+          required SortOrder order}) =>
       RustLib.instance.api.crateApiSearchEngineSearchEngineSearchStream(
-          that: this, query: query, books: books, limit: limit, fuzzy: fuzzy);
+          that: this,
+          query: query,
+          books: books,
+          limit: limit,
+          fuzzy: fuzzy,
+          proximity: proximity,
+          options: options,
+          order: order);
+
+  /// Searches with a query assembled from parts, see [`SearchQuery`].
+  /// Results come in `order`, with their matches highlighted.
+  Future<SearchResponse> searchStructured(
+          {required SearchQuery query,
+          required BookFilter books,
+          required int limit,
+          required MatchOptions options,
+          required SnippetOptions snippetOptions,
+          required SortOrder order}) =>
+      RustLib.instance.api.crateApiSearchEngineSearchEngineSearchStructured(
+          that: this,
+          query: query,
+          books: books,
+          limit: limit,
+          options: options,
+          snippetOptions: snippetOptions,
+          order: order);
 }
@@ -53,6 +53,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex(
           dynamic raw);
 
+  @protected
+  SearchEngine
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+          dynamic raw);
+
   @protected
   BoxQuery
       dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery(
@@ -75,21 +80,202 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  BookCount dco_decode_book_count(dynamic raw);
+
+  @protected
+  BookFilter dco_decode_book_filter(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  BookFilter dco_decode_box_autoadd_book_filter(dynamic raw);
+
+  @protected
+  GematriaOptions dco_decode_box_autoadd_gematria_options(dynamic raw);
+
+  @protected
+  HighlightTags dco_decode_box_autoadd_highlight_tags(dynamic raw);
+
+  @protected
+  MatchOptions dco_decode_box_autoadd_match_options(dynamic raw);
+
+  @protected
+  Proximity dco_decode_box_autoadd_proximity(dynamic raw);
+
+  @protected
+  QueryNode dco_decode_box_autoadd_query_node(dynamic raw);
+
+  @protected
+  ScoreExplanation dco_decode_box_autoadd_score_explanation(dynamic raw);
+
+  @protected
+  SearchCursor dco_decode_box_autoadd_search_cursor(dynamic raw);
+
+  @protected
+  SearchEngineError dco_decode_box_autoadd_search_engine_error(dynamic raw);
+
+  @protected
+  SearchQuery dco_decode_box_autoadd_search_query(dynamic raw);
+
+  @protected
+  SnippetOptions dco_decode_box_autoadd_snippet_options(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  CategoryCount dco_decode_category_count(dynamic raw);
+
+  @protected
+  ClauseOccur dco_decode_clause_occur(dynamic raw);
+
+  @protected
+  double dco_decode_f_32(dynamic raw);
+
+  @protected
+  FieldFilter dco_decode_field_filter(dynamic raw);
+
+  @protected
+  FilterField dco_decode_filter_field(dynamic raw);
+
+  @protected
+  GematriaMethod dco_decode_gematria_method(dynamic raw);
+
+  @protected
+  GematriaOptions dco_decode_gematria_options(dynamic raw);
+
+  @protected
+  Highlight dco_decode_highlight(dynamic raw);
+
+  @protected
+  HighlightTags dco_decode_highlight_tags(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<BookCount> dco_decode_list_book_count(dynamic raw);
+
+  @protected
+  List<CategoryCount> dco_decode_list_category_count(dynamic raw);
+
+  @protected
+  List<FieldFilter> dco_decode_list_field_filter(dynamic raw);
+
+  @protected
+  List<Highlight> dco_decode_list_highlight(dynamic raw);
+
+  @protected
+  List<NeighborSegment> dco_decode_list_neighbor_segment(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<QueryClause> dco_decode_list_query_clause(dynamic raw);
+
+  @protected
+  List<QueryExpansion> dco_decode_list_query_expansion(dynamic raw);
+
+  @protected
+  List<QueryNode> dco_decode_list_query_node(dynamic raw);
+
+  @protected
+  List<ScoreExplanation> dco_decode_list_score_explanation(dynamic raw);
+
   @protected
   List<SearchResult> dco_decode_list_search_result(dynamic raw);
 
+  @protected
+  List<SegmentHighlights> dco_decode_list_segment_highlights(dynamic raw);
+
+  @protected
+  MatchOptions dco_decode_match_options(dynamic raw);
+
+  @protected
+  NeighborSegment dco_decode_neighbor_segment(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  HighlightTags? dco_decode_opt_box_autoadd_highlight_tags(dynamic raw);
+
+  @protected
+  Proximity? dco_decode_opt_box_autoadd_proximity(dynamic raw);
+
+  @protected
+  QueryNode? dco_decode_opt_box_autoadd_query_node(dynamic raw);
+
+  @protected
+  ScoreExplanation? dco_decode_opt_box_autoadd_score_explanation(dynamic raw);
+
+  @protected
+  SearchCursor? dco_decode_opt_box_autoadd_search_cursor(dynamic raw);
+
+  @protected
+  SearchEngineError? dco_decode_opt_box_autoadd_search_engine_error(
+      dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  Proximity dco_decode_proximity(dynamic raw);
+
+  @protected
+  QueryClause dco_decode_query_clause(dynamic raw);
+
+  @protected
+  QueryExpansion dco_decode_query_expansion(dynamic raw);
+
+  @protected
+  QueryExplanation dco_decode_query_explanation(dynamic raw);
+
+  @protected
+  QueryNode dco_decode_query_node(dynamic raw);
+
+  @protected
+  QueryNodeKind dco_decode_query_node_kind(dynamic raw);
+
+  @protected
+  ScoreExplanation dco_decode_score_explanation(dynamic raw);
+
+  @protected
+  SearchCursor dco_decode_search_cursor(dynamic raw);
+
+  @protected
+  SearchEngineError dco_decode_search_engine_error(dynamic raw);
+
+  @protected
+  SearchEngineErrorKind dco_decode_search_engine_error_kind(dynamic raw);
+
+  @protected
+  SearchQuery dco_decode_search_query(dynamic raw);
+
+  @protected
+  SearchResponse dco_decode_search_response(dynamic raw);
+
   @protected
   SearchResult dco_decode_search_result(dynamic raw);
 
+  @protected
+  SegmentHighlights dco_decode_segment_highlights(dynamic raw);
+
+  @protected
+  SnippetBoundary dco_decode_snippet_boundary(dynamic raw);
+
+  @protected
+  SnippetOptions dco_decode_snippet_options(dynamic raw);
+
+  @protected
+  SortOrder dco_decode_sort_order(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -128,6 +314,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex(
           SseDeserializer deserializer);
 
+  @protected
+  SearchEngine
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+          SseDeserializer deserializer);
+
   @protected
   BoxQuery
       sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery(
@@ -151,22 +342,221 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  BookCount sse_decode_book_count(SseDeserializer deserializer);
+
+  @protected
+  BookFilter sse_decode_book_filter(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  BookFilter sse_decode_box_autoadd_book_filter(SseDeserializer deserializer);
+
+  @protected
+  GematriaOptions sse_decode_box_autoadd_gematria_options(
+      SseDeserializer deserializer);
+
+  @protected
+  HighlightTags sse_decode_box_autoadd_highlight_tags(
+      SseDeserializer deserializer);
+
+  @protected
+  MatchOptions sse_decode_box_autoadd_match_options(
+      SseDeserializer deserializer);
+
+  @protected
+  Proximity sse_decode_box_autoadd_proximity(SseDeserializer deserializer);
+
+  @protected
+  QueryNode sse_decode_box_autoadd_query_node(SseDeserializer deserializer);
+
+  @protected
+  ScoreExplanation sse_decode_box_autoadd_score_explanation(
+      SseDeserializer deserializer);
+
+  @protected
+  SearchCursor sse_decode_box_autoadd_search_cursor(
+      SseDeserializer deserializer);
+
+  @protected
+  SearchEngineError sse_decode_box_autoadd_search_engine_error(
+      SseDeserializer deserializer);
+
+  @protected
+  SearchQuery sse_decode_box_autoadd_search_query(SseDeserializer deserializer);
+
+  @protected
+  SnippetOptions sse_decode_box_autoadd_snippet_options(
+      SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  CategoryCount sse_decode_category_count(SseDeserializer deserializer);
+
+  @protected
+  ClauseOccur sse_decode_clause_occur(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  FieldFilter sse_decode_field_filter(SseDeserializer deserializer);
+
+  @protected
+  FilterField sse_decode_filter_field(SseDeserializer deserializer);
+
+  @protected
+  GematriaMethod sse_decode_gematria_method(SseDeserializer deserializer);
+
+  @protected
+  GematriaOptions sse_decode_gematria_options(SseDeserializer deserializer);
+
+  @protected
+  Highlight sse_decode_highlight(SseDeserializer deserializer);
+
+  @protected
+  HighlightTags sse_decode_highlight_tags(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<BookCount> sse_decode_list_book_count(SseDeserializer deserializer);
+
+  @protected
+  List<CategoryCount> sse_decode_list_category_count(
+      SseDeserializer deserializer);
+
+  @protected
+  List<FieldFilter> sse_decode_list_field_filter(SseDeserializer deserializer);
+
+  @protected
+  List<Highlight> sse_decode_list_highlight(SseDeserializer deserializer);
+
+  @protected
+  List<NeighborSegment> sse_decode_list_neighbor_segment(
+      SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<QueryClause> sse_decode_list_query_clause(SseDeserializer deserializer);
+
+  @protected
+  List<QueryExpansion> sse_decode_list_query_expansion(
+      SseDeserializer deserializer);
+
+  @protected
+  List<QueryNode> sse_decode_list_query_node(SseDeserializer deserializer);
+
+  @protected
+  List<ScoreExplanation> sse_decode_list_score_explanation(
+      SseDeserializer deserializer);
+
   @protected
   List<SearchResult> sse_decode_list_search_result(
       SseDeserializer deserializer);
 
+  @protected
+  List<SegmentHighlights> sse_decode_list_segment_highlights(
+      SseDeserializer deserializer);
+
+  @protected
+  MatchOptions sse_decode_match_options(SseDeserializer deserializer);
+
+  @protected
+  NeighborSegment sse_decode_neighbor_segment(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  HighlightTags? sse_decode_opt_box_autoadd_highlight_tags(
+      SseDeserializer deserializer);
+
+  @protected
+  Proximity? sse_decode_opt_box_autoadd_proximity(SseDeserializer deserializer);
+
+  @protected
+  QueryNode? sse_decode_opt_box_autoadd_query_node(
+      SseDeserializer deserializer);
+
+  @protected
+  ScoreExplanation? sse_decode_opt_box_autoadd_score_explanation(
+      SseDeserializer deserializer);
+
+  @protected
+  SearchCursor? sse_decode_opt_box_autoadd_search_cursor(
+      SseDeserializer deserializer);
+
+  @protected
+  SearchEngineError? sse_decode_opt_box_autoadd_search_engine_error(
+      SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  Proximity sse_decode_proximity(SseDeserializer deserializer);
+
+  @protected
+  QueryClause sse_decode_query_clause(SseDeserializer deserializer);
+
+  @protected
+  QueryExpansion sse_decode_query_expansion(SseDeserializer deserializer);
+
+  @protected
+  QueryExplanation sse_decode_query_explanation(SseDeserializer deserializer);
+
+  @protected
+  QueryNode sse_decode_query_node(SseDeserializer deserializer);
+
+  @protected
+  QueryNodeKind sse_decode_query_node_kind(SseDeserializer deserializer);
+
+  @protected
+  ScoreExplanation sse_decode_score_explanation(SseDeserializer deserializer);
+
+  @protected
+  SearchCursor sse_decode_search_cursor(SseDeserializer deserializer);
+
+  @protected
+  SearchEngineError sse_decode_search_engine_error(
+      SseDeserializer deserializer);
+
+  @protected
+  SearchEngineErrorKind sse_decode_search_engine_error_kind(
+      SseDeserializer deserializer);
+
+  @protected
+  SearchQuery sse_decode_search_query(SseDeserializer deserializer);
+
+  @protected
+  SearchResponse sse_decode_search_response(SseDeserializer deserializer);
+
   @protected
   SearchResult sse_decode_search_result(SseDeserializer deserializer);
 
+  @protected
+  SegmentHighlights sse_decode_segment_highlights(SseDeserializer deserializer);
+
+  @protected
+  SnippetBoundary sse_decode_snippet_boundary(SseDeserializer deserializer);
+
+  @protected
+  SnippetOptions sse_decode_snippet_options(SseDeserializer deserializer);
+
+  @protected
+  SortOrder sse_decode_sort_order(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
@@ -182,9 +572,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
-
   @protected
   void sse_encode_AnyhowException(
       AnyhowException self, SseSerializer serializer);
@@ -209,6 +596,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerIndex(
           Index self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchEngine(
+          SearchEngine self, SseSerializer serializer);
+
   @protected
   void
       sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBoxdynQuery(
@@ -231,23 +623,242 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_book_count(BookCount self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_book_filter(BookFilter self, SseSerializer serializer);
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_book_filter(
+      BookFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_gematria_options(
+      GematriaOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_highlight_tags(
+      HighlightTags self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_match_options(
+      MatchOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_proximity(
+      Proximity self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_query_node(
+      QueryNode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_score_explanation(
+      ScoreExplanation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_search_cursor(
+      SearchCursor self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_search_engine_error(
+      SearchEngineError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_search_query(
+      SearchQuery self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_snippet_options(
+      SnippetOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_category_count(CategoryCount self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_clause_occur(ClauseOccur self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_field_filter(FieldFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_filter_field(FilterField self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_gematria_method(
+      GematriaMethod self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_gematria_options(
+      GematriaOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_highlight(Highlight self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_highlight_tags(HighlightTags self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_book_count(
+      List<BookCount> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_category_count(
+      List<CategoryCount> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_field_filter(
+      List<FieldFilter> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_highlight(
+      List<Highlight> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_neighbor_segment(
+      List<NeighborSegment> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_query_clause(
+      List<QueryClause> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_query_expansion(
+      List<QueryExpansion> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_query_node(
+      List<QueryNode> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_score_explanation(
+      List<ScoreExplanation> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_search_result(
       List<SearchResult> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_segment_highlights(
+      List<SegmentHighlights> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_match_options(MatchOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_neighbor_segment(
+      NeighborSegment self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_highlight_tags(
+      HighlightTags? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_proximity(
+      Proximity? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_query_node(
+      QueryNode? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_score_explanation(
+      ScoreExplanation? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_search_cursor(
+      SearchCursor? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_search_engine_error(
+      SearchEngineError? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_proximity(Proximity self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_query_clause(QueryClause self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_query_expansion(
+      QueryExpansion self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_query_explanation(
+      QueryExplanation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_query_node(QueryNode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_query_node_kind(QueryNodeKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_score_explanation(
+      ScoreExplanation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_cursor(SearchCursor self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_engine_error(
+      SearchEngineError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_engine_error_kind(
+      SearchEngineErrorKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_query(SearchQuery self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_response(
+      SearchResponse self, SseSerializer serializer);
+
   @protected
   void sse_encode_search_result(SearchResult self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_segment_highlights(
+      SegmentHighlights self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_snippet_boundary(
+      SnippetBoundary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_snippet_options(
+      SnippetOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sort_order(SortOrder self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -262,9 +873,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -0,0 +1,98 @@
+import 'dart:async';
+
+import 'rust/api/search_engine.dart';
+
+/// A [SearchEngineError] as an exception of its own type for each
+/// [SearchEngineErrorKind], so callers can catch the kinds they handle with
+/// `on`, e.g. `on IndexLockedException`.
+///
+/// The engine's functions fail with [SearchEngineError]; use
+/// [SearchEngineErrors.withExceptions] on the futures and streams they return
+/// to fail with these instead.
+sealed class SearchEngineException implements Exception {
+  const SearchEngineException(this.error);
+
+  /// The exception of the kind of `error`.
+  factory SearchEngineException.of(SearchEngineError error) =>
+      switch (error.kind) {
+        SearchEngineErrorKind.indexLocked => IndexLockedException(error),
+        SearchEngineErrorKind.corruptIndex => CorruptIndexException(error),
+        SearchEngineErrorKind.invalidQuery => InvalidQueryException(error),
+        SearchEngineErrorKind.schemaMismatch => SchemaMismatchException(error),
+        SearchEngineErrorKind.io => SearchEngineIoException(error),
+        SearchEngineErrorKind.other => OtherSearchEngineException(error),
+      };
+
+  /// The error as returned by the engine.
+  final SearchEngineError error;
+
+  String get message => error.message;
+
+  @override
+  String toString() => '$runtimeType: $message';
+}
+
+/// Another writer, usually another instance of the app, holds the index.
+final class IndexLockedException extends SearchEngineException {
+  const IndexLockedException(super.error);
+}
+
+/// Index files are damaged or were written by an incompatible version.
+final class CorruptIndexException extends SearchEngineException {
+  const CorruptIndexException(super.error);
+}
+
+/// The query could not be parsed or asks for more than is supported.
+final class InvalidQueryException extends SearchEngineException {
+  const InvalidQueryException(super.error);
+
+  /// The character offset of the first error in the query as rewritten for
+  /// the query parser, see [QueryExplanation.parsedQuery], when it is known.
+  int? get position => error.position;
+}
+
+/// The index on disk was created with different fields; it has to be deleted
+/// and rebuilt.
+final class SchemaMismatchException extends SearchEngineException {
+  const SchemaMismatchException(super.error);
+}
+
+/// Reading or writing the index or a dictionary file failed.
+final class SearchEngineIoException extends SearchEngineException {
+  const SearchEngineIoException(super.error);
+}
+
+/// Any other failure of the engine.
+final class OtherSearchEngineException extends SearchEngineException {
+  const OtherSearchEngineException(super.error);
+}
+
+/// Turns the [SearchEngineError]s a future fails with into
+/// [SearchEngineException]s.
+extension SearchEngineErrors<T> on Future<T> {
+  /// This future, failing with the [SearchEngineException] of the kind of
+  /// the [SearchEngineError] it fails with.
+  Future<T> withExceptions() => onError<SearchEngineError>(
+        (error, stackTrace) => Error.throwWithStackTrace(
+          SearchEngineException.of(error),
+          stackTrace,
+        ),
+      );
+}
+
+/// Turns the [SearchEngineError]s a stream emits into
+/// [SearchEngineException]s.
+extension SearchEngineStreamErrors<T> on Stream<T> {
+  /// This stream, emitting the [SearchEngineException] of the kind of each
+  /// [SearchEngineError] it emits.
+  Stream<T> withExceptions() => transform(
+        StreamTransformer<T, T>.fromHandlers(
+          handleError: (error, stackTrace, sink) => sink.addError(
+            error is SearchEngineError
+                ? SearchEngineException.of(error)
+                : error,
+            stackTrace,
+          ),
+        ),
+      );
+}
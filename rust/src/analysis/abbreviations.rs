@@ -0,0 +1,215 @@
+use super::normalize::{GERESH, GERSHAYIM};
+use super::tokenizer::HebrewTokenizer;
+use crate::api::search_engine::{MatchOptions, QueryExpansion};
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// The dictionary consulted by every query, replaced by [`load`].
+static DICTIONARY: Lazy<RwLock<AbbreviationDictionary>> = Lazy::new(Default::default);
+
+/// Abbreviations (ראשי תיבות) and their expansions, looked up in both
+/// directions: "רש"י" expands to "רבי שלמה יצחקי" and the other way around.
+#[derive(Debug, Default)]
+pub struct AbbreviationDictionary {
+    // searchable form of the words -> alternatives, as written in the file
+    alternatives: HashMap<String, Vec<String>>,
+    // number of words of the longest key
+    max_words: usize,
+    // number of lines read from the file
+    entries: usize,
+}
+
+impl AbbreviationDictionary {
+    /// Parses a dictionary file: one abbreviation per line, followed by `=`
+    /// and its expansions separated by `|`. Blank lines and lines starting
+    /// with `#` are ignored.
+    pub fn parse(contents: &str) -> Self {
+        let mut dictionary = AbbreviationDictionary::default();
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((abbreviation, expansions)) = line.split_once('=') else {
+                continue;
+            };
+            dictionary.entries += 1;
+            let abbreviation = super::normalize_query(abbreviation.trim());
+            for expansion in expansions.split('|').map(str::trim) {
+                if expansion.is_empty() {
+                    continue;
+                }
+                let expansion = super::normalize_query(expansion);
+                dictionary.insert(&abbreviation, &expansion);
+                dictionary.insert(&expansion, &abbreviation);
+            }
+        }
+        dictionary
+    }
+
+    fn insert(&mut self, words: &str, alternative: &str) {
+        self.max_words = self.max_words.max(words.split_whitespace().count());
+        let alternatives = self.alternatives.entry(searchable_form(words)).or_default();
+        if !alternatives.iter().any(|a| a == alternative) {
+            alternatives.push(alternative.to_string());
+        }
+    }
+
+    /// Rewrites every dictionary entry found among the plain words of
+    /// `query` into an OR of the words as typed and their alternatives, and
+    /// reports what was added. Words inside quoted phrases or carrying query
+    /// syntax are left alone.
+    pub fn expand(&self, query: &str) -> (String, Vec<QueryExpansion>) {
+        let words: Vec<&str> = query.split_whitespace().collect();
+        let mut rewritten = Vec::new();
+        let mut expansions = Vec::new();
+        let mut in_phrase = false;
+        let mut i = 0;
+        while i < words.len() {
+            match self.longest_match(&words[i..]).filter(|_| !in_phrase) {
+                Some((len, alternatives)) => {
+                    let original = words[i..i + len].join(" ");
+                    let clauses: Vec<String> = std::iter::once(&original)
+                        .chain(alternatives)
+                        .map(|words| quoted(words))
+                        .collect();
+                    rewritten.push(format!("({})", clauses.join(" OR ")));
+                    expansions.push(QueryExpansion {
+                        original,
+                        alternatives: alternatives.clone(),
+                    });
+                    i += len;
+                }
+                None => {
+                    in_phrase ^= words[i].matches('"').count() % 2 == 1;
+                    rewritten.push(words[i].to_string());
+                    i += 1;
+                }
+            }
+        }
+        (rewritten.join(" "), expansions)
+    }
+
+    /// The longest run of plain words at the start of `words` that is in
+    /// the dictionary, with its length in words.
+    fn longest_match(&self, words: &[&str]) -> Option<(usize, &Vec<String>)> {
+        let plain = words.iter().take_while(|word| is_plain(word)).count();
+        (1..=plain.min(self.max_words)).rev().find_map(|len| {
+            self.alternatives
+                .get(&searchable_form(&words[..len].join(" ")))
+                .map(|alternatives| (len, alternatives))
+        })
+    }
+}
+
+/// Replaces the dictionary used by all queries with the one in the file at
+/// `path`, returning its number of entries.
+pub fn load(path: &str) -> Result<usize> {
+    let dictionary = AbbreviationDictionary::parse(&std::fs::read_to_string(path)?);
+    let entries = dictionary.entries;
+    *DICTIONARY.write().unwrap() = dictionary;
+    Ok(entries)
+}
+
+/// Expands `query` with the loaded dictionary, see
+/// [`AbbreviationDictionary::expand`].
+pub fn expand(query: &str) -> (String, Vec<QueryExpansion>) {
+    DICTIONARY.read().unwrap().expand(query)
+}
+
+/// Words are compared by the form the analyzer searches for, so spelling
+/// variants such as רש"י, רש״י and רשי share one entry.
+fn searchable_form(words: &str) -> String {
+    HebrewTokenizer::for_query(MatchOptions::default())
+        .tokenize(words)
+        .into_iter()
+        .map(|token| token.text)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A word made only of letters, digits and abbreviation marks, as opposed to
+/// one carrying query syntax such as `+`, `"` or `field:`.
+fn is_plain(word: &str) -> bool {
+    word.chars()
+        .all(|c| c.is_alphanumeric() || matches!(c, GERESH | GERSHAYIM))
+}
+
+/// Query syntax for `words`: a bare word, or a phrase for several.
+fn quoted(words: &str) -> String {
+    if words.contains(char::is_whitespace) {
+        format!("\"{words}\"")
+    } else {
+        words.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AbbreviationDictionary;
+
+    const DICTIONARY: &str = "
+# abbreviations of the commentators
+
+רש\"י = רבי שלמה יצחקי
+רמב\"ם = רבי משה בן מימון | הרמב\"ם
+not an entry
+";
+
+    /// `query` expanded with [`DICTIONARY`], with the words it expanded and
+    /// their alternatives.
+    fn expand(query: &str) -> (String, Vec<(String, Vec<String>)>) {
+        let (rewritten, expansions) = AbbreviationDictionary::parse(DICTIONARY).expand(query);
+        let expansions = expansions
+            .into_iter()
+            .map(|expansion| (expansion.original, expansion.alternatives))
+            .collect();
+        (rewritten, expansions)
+    }
+
+    #[test]
+    fn only_entries_are_read() {
+        assert_eq!(AbbreviationDictionary::parse(DICTIONARY).entries, 2);
+        assert_eq!(
+            AbbreviationDictionary::parse("\n# רש\"י = רשי\n").entries,
+            0
+        );
+    }
+
+    #[test]
+    fn abbreviations_and_expansions_find_each_other() {
+        assert_eq!(
+            expand("דברי רש״י כאן"),
+            (
+                "דברי (רש״י OR \"רבי שלמה יצחקי\") כאן".to_string(),
+                vec![("רש״י".to_string(), vec!["רבי שלמה יצחקי".to_string()])]
+            )
+        );
+        assert_eq!(
+            expand("רבי שלמה יצחקי אמר").0,
+            "(\"רבי שלמה יצחקי\" OR רש״י) אמר"
+        );
+        assert_eq!(
+            expand("רמבם").1,
+            [(
+                "רמבם".to_string(),
+                vec!["רבי משה בן מימון".to_string(), "הרמב״ם".to_string()]
+            )]
+        );
+        // the longest entry wins over the words it starts with
+        assert_eq!(
+            expand("רבי משה בן מימון").0,
+            "(\"רבי משה בן מימון\" OR רמב״ם)"
+        );
+    }
+
+    #[test]
+    fn phrases_and_query_syntax_are_left_alone() {
+        assert_eq!(
+            expand("\"אמר רש״י כאן\" רש״י").0,
+            "\"אמר רש״י כאן\" (רש״י OR \"רבי שלמה יצחקי\")"
+        );
+        assert_eq!(expand("+רש״י -רמב״ם"), ("+רש״י -רמב״ם".to_string(), vec![]));
+    }
+}
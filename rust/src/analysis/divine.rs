@@ -0,0 +1,81 @@
+use super::prefixes;
+
+/// Marks the tokens of divine names brought to a common spelling, so they
+/// are only matched when divine-name equivalence is asked for and a search
+/// for one spelling otherwise finds that spelling alone.
+pub const DIVINE_MARK: char = '^';
+
+/// Spellings of the Tetragrammaton, as the tokenizer sees them: geresh kept,
+/// gershayim dropped and spelled-out letters joined.
+const TETRAGRAMMATON: &[&str] = &["יהוה", "יקוק", "ידוד", "ה׳"];
+
+/// The common spelling of the Tetragrammaton.
+const TETRAGRAMMATON_FORM: &str = "יהוה";
+
+/// Spellings of the stem of "אלהים" and its inflections (אלהינו, אלקיך),
+/// each followed by a yud: אלה, אלק, אלוה, אלוק.
+const ELOHIM_STEMS: &[&str] = &["אלה", "אלק", "אלוה", "אלוק"];
+
+/// The common spelling of the stem of "אלהים".
+const ELOHIM_STEM_FORM: &str = "אלה";
+
+/// `word` with the divine name it contains, after any prefix letters,
+/// respelled in a common form: "לה׳" gives "ליהוה", "ואלקינו" gives
+/// "ואלהינו". Returns `None` for words that are not divine names.
+pub fn canonical(word: &str) -> Option<String> {
+    let prefix_ends = std::iter::once(0).chain(word.char_indices().skip(1).map(|(i, _)| i));
+    for end in prefix_ends.take(prefixes::MAX_PREFIX_LEN + 1) {
+        let (prefix, name) = word.split_at(end);
+        if !prefix.chars().all(prefixes::is_prefix_letter) {
+            break;
+        }
+        if TETRAGRAMMATON.contains(&name) {
+            return Some(format!("{prefix}{TETRAGRAMMATON_FORM}"));
+        }
+        for stem in ELOHIM_STEMS {
+            if let Some(rest) = name.strip_prefix(stem).filter(|rest| rest.starts_with('י')) {
+                return Some(format!("{prefix}{ELOHIM_STEM_FORM}{rest}"));
+            }
+        }
+    }
+    None
+}
+
+/// The form a divine name in its common spelling is indexed and looked up in.
+pub fn marked(form: &str) -> String {
+    format!("{DIVINE_MARK}{form}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::canonical;
+
+    #[test]
+    fn tetragrammaton_spellings_are_respelled() {
+        for spelling in ["יהוה", "יקוק", "ידוד", "ה׳"] {
+            assert_eq!(canonical(spelling).as_deref(), Some("יהוה"), "{spelling}");
+        }
+    }
+
+    #[test]
+    fn elohim_spellings_keep_their_inflection() {
+        assert_eq!(canonical("אלקים").as_deref(), Some("אלהים"));
+        assert_eq!(canonical("אלוקינו").as_deref(), Some("אלהינו"));
+        assert_eq!(canonical("אלוהיך").as_deref(), Some("אלהיך"));
+    }
+
+    #[test]
+    fn prefix_letters_are_kept() {
+        assert_eq!(canonical("לה׳").as_deref(), Some("ליהוה"));
+        assert_eq!(canonical("ואלקינו").as_deref(), Some("ואלהינו"));
+        assert_eq!(canonical("וביקוק").as_deref(), Some("וביהוה"));
+    }
+
+    #[test]
+    fn other_words_are_not_divine_names() {
+        assert_eq!(canonical("אלה"), None);
+        assert_eq!(canonical("אלקנה"), None);
+        assert_eq!(canonical("תה׳"), None);
+        assert_eq!(canonical("שלום"), None);
+    }
+}
@@ -0,0 +1,181 @@
+use super::normalize::{is_hebrew_letter, NormalizedText};
+use super::tokenizer::split_words;
+use crate::api::search_engine::GematriaMethod;
+use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
+
+/// Longest run of consecutive words whose total value is indexed.
+pub const MAX_PHRASE_WORDS: usize = 3;
+
+/// Every method values are indexed with.
+const METHODS: [GematriaMethod; 3] = [
+    GematriaMethod::Standard,
+    GematriaMethod::Small,
+    GematriaMethod::Large,
+];
+
+/// Standard values of the letters א to ת in code point order, final forms
+/// included.
+const LETTER_VALUES: [u32; 27] = [
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 20, 20, 30, 40, 40, 50, 50, 60, 70, 80, 80, 90, 90, 100, 200,
+    300, 400,
+];
+
+/// Values of the final forms ך, ם, ן, ף and ץ in the large count.
+const LARGE_FINAL_VALUES: [(char, u32); 5] =
+    [('ך', 500), ('ם', 600), ('ן', 700), ('ף', 800), ('ץ', 900)];
+
+/// The gematria of `word` by `method`, counting its Hebrew letters only.
+pub fn value(word: &str, method: GematriaMethod) -> u32 {
+    word.chars()
+        .filter(|c| is_hebrew_letter(*c))
+        .map(|c| letter_value(c, method))
+        .sum()
+}
+
+/// The value of the Hebrew letter `c` by `method`.
+pub fn letter_value(c: char, method: GematriaMethod) -> u32 {
+    let standard = LETTER_VALUES[(c as u32 - 'א' as u32) as usize];
+    match method {
+        GematriaMethod::Standard => standard,
+        GematriaMethod::Small => match standard {
+            100.. => standard / 100,
+            10.. => standard / 10,
+            _ => standard,
+        },
+        GematriaMethod::Large => LARGE_FINAL_VALUES
+            .iter()
+            .find(|(last, _)| *last == c)
+            .map_or(standard, |(_, value)| *value),
+    }
+}
+
+/// The term under which runs of `words` words totalling `value` by `method`
+/// are indexed, e.g. "s2:358".
+pub fn term(method: GematriaMethod, words: usize, value: u32) -> String {
+    let method = match method {
+        GematriaMethod::Standard => 's',
+        GematriaMethod::Small => 'k',
+        GematriaMethod::Large => 'g',
+    };
+    format!("{method}{words}:{value}")
+}
+
+/// Indexes the gematria of every word and of every run of up to
+/// [`MAX_PHRASE_WORDS`] consecutive words, by every method. A run's token
+/// spans its words, so highlighting it marks the whole phrase. Words without
+/// Hebrew letters have no value and break runs.
+#[derive(Clone, Default)]
+pub struct GematriaTokenizer;
+
+pub struct GematriaTokenStream {
+    tokens: std::vec::IntoIter<Token>,
+    token: Token,
+}
+
+impl GematriaTokenizer {
+    pub fn tokenize(&self, text: &str) -> Vec<Token> {
+        let normalized = NormalizedText::new(text).strip_html().fold();
+        let words = split_words(&normalized);
+        let mut tokens = Vec::new();
+        for (start, first) in words.iter().enumerate() {
+            let run = words[start..].iter().take(MAX_PHRASE_WORDS);
+            let run: Vec<&Token> = run
+                .take_while(|word| value(&word.text, GematriaMethod::Standard) > 0)
+                .collect();
+            for len in 1..=run.len() {
+                for method in METHODS {
+                    let total = run[..len].iter().map(|word| value(&word.text, method));
+                    tokens.push(Token {
+                        offset_from: first.offset_from,
+                        offset_to: run[len - 1].offset_to,
+                        position: first.position,
+                        text: term(method, len, total.sum()),
+                        position_length: len,
+                    });
+                }
+            }
+        }
+        tokens
+    }
+}
+
+impl Tokenizer for GematriaTokenizer {
+    type TokenStream<'a> = GematriaTokenStream;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> GematriaTokenStream {
+        GematriaTokenStream {
+            tokens: self.tokenize(text).into_iter(),
+            token: Token::default(),
+        }
+    }
+}
+
+impl TokenStream for GematriaTokenStream {
+    fn advance(&mut self) -> bool {
+        match self.tokens.next() {
+            Some(token) => {
+                self.token = token;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GematriaTokenizer;
+
+    /// The terms of the tokens of `text`, each with the text it spans.
+    fn terms(text: &str) -> Vec<(String, &str)> {
+        GematriaTokenizer
+            .tokenize(text)
+            .into_iter()
+            .map(|token| (token.text, &text[token.offset_from..token.offset_to]))
+            .collect()
+    }
+
+    fn has(text: &str, term: &str, span: &str) -> bool {
+        terms(text).contains(&(term.to_string(), span))
+    }
+
+    #[test]
+    fn runs_are_valued_by_every_method() {
+        let text = "שלום עליכם";
+        assert!(has(text, "s1:376", "שלום"));
+        assert!(has(text, "k1:16", "שלום"));
+        assert!(has(text, "g1:936", "שלום"));
+        assert!(has(text, "s2:546", "שלום עליכם"));
+        assert!(has(text, "g2:1666", "שלום עליכם"));
+        assert!(has(text, "s1:170", "עליכם"));
+    }
+
+    #[test]
+    fn runs_are_at_most_three_words_long() {
+        let text = "א ב ג ד";
+        assert!(has(text, "s3:6", "א ב ג"));
+        assert!(has(text, "s3:9", "ב ג ד"));
+        assert!(!terms(text).iter().any(|(term, _)| term.starts_with("s4:")));
+    }
+
+    #[test]
+    fn words_without_hebrew_letters_break_runs() {
+        let text = "א 12 ב";
+        assert!(has(text, "s1:1", "א"));
+        assert!(has(text, "s1:2", "ב"));
+        assert!(!terms(text).iter().any(|(term, _)| term.starts_with("s2:")));
+    }
+
+    #[test]
+    fn markup_is_not_counted() {
+        assert!(has("<b>אב</b> ג", "s2:6", "אב</b> ג"));
+    }
+}
@@ -0,0 +1,117 @@
+pub mod abbreviations;
+mod divine;
+pub mod gematria;
+mod normalize;
+pub mod numerals;
+mod prefixes;
+pub mod roots;
+mod spelling;
+#[cfg(test)]
+mod tests;
+mod tokenizer;
+
+pub use gematria::GematriaTokenizer;
+pub use normalize::NormalizedText;
+pub use tokenizer::HebrewTokenizer;
+
+use crate::api::search_engine::MatchOptions;
+use tantivy::index::Index;
+use tantivy::tokenizer::{LowerCaser, RemoveLongFilter, TextAnalyzer, TokenizerManager};
+
+/// Name under which the Hebrew analyzer is registered on the index.
+pub const HEBREW_TOKENIZER: &str = "hebrew";
+
+/// Name of the analyzer indexing the consonantal skeleton of Hebrew words,
+/// which plene and defective spellings share.
+pub const SKELETON_TOKENIZER: &str = "hebrew_skeleton";
+
+/// Name of the analyzer indexing the candidate roots of Hebrew words.
+pub const ROOT_TOKENIZER: &str = "hebrew_root";
+
+/// Name of the analyzer indexing the gematria of words and short phrases.
+pub const GEMATRIA_TOKENIZER: &str = "gematria";
+
+/// Registers the analyzers referenced by the schema. Tokenizers are not
+/// persisted with the index, so this has to run every time it is opened.
+pub fn register_tokenizers(index: &Index) {
+    index.tokenizers().register(
+        HEBREW_TOKENIZER,
+        hebrew_analyzer(HebrewTokenizer::for_index()),
+    );
+    index.tokenizers().register(
+        SKELETON_TOKENIZER,
+        hebrew_analyzer(HebrewTokenizer::for_index().skeleton()),
+    );
+    index.tokenizers().register(
+        ROOT_TOKENIZER,
+        hebrew_analyzer(HebrewTokenizer::for_index().roots()),
+    );
+    index
+        .tokenizers()
+        .register(GEMATRIA_TOKENIZER, GematriaTokenizer);
+}
+
+/// Whether `term` is one of the forms the analyzers index besides the words
+/// as written: stems without their prefix letters, divine names and numbers.
+pub fn is_marked(term: &str) -> bool {
+    term.starts_with(is_mark)
+}
+
+/// Whether `c` is the mark of one of the forms told apart by [`is_marked`].
+/// Marks are ASCII, so never a byte of another character.
+pub fn is_mark(c: char) -> bool {
+    [
+        prefixes::STEM_MARK,
+        divine::DIVINE_MARK,
+        numerals::NUMBER_MARK,
+    ]
+    .contains(&c)
+}
+
+/// `term` without the mark of the forms told apart by [`is_marked`].
+pub fn unmarked(term: &str) -> &str {
+    if is_marked(term) {
+        &term[1..]
+    } else {
+        term
+    }
+}
+
+/// Analyzers used to turn query text into terms. They produce the one form
+/// of each word that `options` asks to match, where the index analyzers
+/// produce every form.
+pub fn query_tokenizers(options: MatchOptions) -> TokenizerManager {
+    let tokenizers = TokenizerManager::default();
+    tokenizers.register(
+        HEBREW_TOKENIZER,
+        hebrew_analyzer(HebrewTokenizer::for_query(options)),
+    );
+    tokenizers.register(
+        SKELETON_TOKENIZER,
+        hebrew_analyzer(HebrewTokenizer::for_query(options).skeleton()),
+    );
+    tokenizers.register(
+        ROOT_TOKENIZER,
+        hebrew_analyzer(HebrewTokenizer::for_query(options).roots()),
+    );
+    tokenizers
+}
+
+/// Rewrites quotes inside words of a query to gershayim and geresh before it
+/// is parsed, so "רמב"ם" is read as one word rather than as a phrase.
+pub fn normalize_query(query: &str) -> String {
+    NormalizedText::new(query).fold().as_str().to_string()
+}
+
+/// Stored `text` without its markup, as the analyzers read it when indexing,
+/// so the offsets of its tokens map onto it.
+pub fn plain_text(text: &str) -> NormalizedText {
+    NormalizedText::new(text).strip_html()
+}
+
+fn hebrew_analyzer(tokenizer: HebrewTokenizer) -> TextAnalyzer {
+    TextAnalyzer::builder(tokenizer)
+        .filter(RemoveLongFilter::limit(40))
+        .filter(LowerCaser)
+        .build()
+}
@@ -0,0 +1,354 @@
+use std::ops::Range;
+use unicode_normalization::char::{decompose_compatible, is_combining_mark};
+
+/// HEBREW PUNCTUATION GERESH, marking abbreviations such as ר׳ and ע׳.
+pub const GERESH: char = '\u{05F3}';
+
+/// HEBREW PUNCTUATION GERSHAYIM, marking acronyms such as רמב״ם and רש״י.
+pub const GERSHAYIM: char = '\u{05F4}';
+
+/// Characters typed in place of a gershayim: the ASCII and typographic
+/// double quotes (a pair of ASCII apostrophes is handled separately).
+const GERSHAYIM_LIKE: &[char] = &['"', '\u{201C}', '\u{201D}'];
+
+/// Characters typed in place of a geresh.
+const GERESH_LIKE: &[char] = &['\'', '\u{2018}', '\u{2019}'];
+
+/// Longest word, in letters, whose trailing apostrophe is read as a geresh
+/// (ר', ע', שו"ע'). Longer words are more likely closing a quotation.
+const MAX_GERESH_WORD_LEN: usize = 3;
+
+/// Fewest letters a hyphenated, letter-by-letter spelling is joined from,
+/// so a two-letter range such as "א-ב" is left alone.
+const MIN_SPELLED_OUT_LETTERS: usize = 3;
+
+/// Hyphen and maqaf, both used between spelled-out letters.
+const HYPHENS: &[char] = &['-', '\u{05BE}'];
+
+/// Tags that start a new line or block, read as a space between words, as
+/// opposed to inline formatting such as <b> and <small>, which may fall
+/// inside a word.
+const BLOCK_TAGS: &[&str] = &[
+    "br",
+    "p",
+    "div",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "li",
+    "ul",
+    "ol",
+    "table",
+    "tr",
+    "td",
+    "th",
+    "hr",
+    "blockquote",
+];
+
+/// Longest name of a character entity decoded, between "&" and ";".
+const MAX_ENTITY_LEN: usize = 10;
+
+pub fn is_hebrew_letter(c: char) -> bool {
+    ('\u{05D0}'..='\u{05EA}').contains(&c)
+}
+
+/// Format characters that do not show in text but would split or change a
+/// word: directional marks and embeddings, zero-width spaces and joiners, the
+/// soft hyphen and the byte order mark.
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{061C}'
+            | '\u{180E}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{FEFF}'
+    )
+}
+
+/// Text rewritten by a normalization pass, remembering for every character of
+/// the rewritten text the byte range of the original text it came from, so
+/// token offsets can be mapped back onto the stored (original) text.
+#[derive(Clone, Debug, Default)]
+pub struct NormalizedText {
+    text: String,
+    // (byte offset in `text`, byte range in the original text), one per char
+    spans: Vec<(usize, Range<usize>)>,
+}
+
+impl NormalizedText {
+    /// Identity normalization: every char maps onto itself.
+    pub fn new(original: &str) -> Self {
+        let mut normalized = NormalizedText::default();
+        for (offset, c) in original.char_indices() {
+            normalized.push(c, offset..offset + c.len_utf8());
+        }
+        normalized
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Appends `c`, recording that it originates from `origin`.
+    pub fn push(&mut self, c: char, origin: Range<usize>) {
+        self.spans.push((self.text.len(), origin));
+        self.text.push(c);
+    }
+
+    /// Iterates over the chars of the normalized text with their origin.
+    pub fn chars(&self) -> impl Iterator<Item = (char, Range<usize>)> + '_ {
+        self.text
+            .chars()
+            .zip(self.spans.iter())
+            .map(|(c, (_, origin))| (c, origin.clone()))
+    }
+
+    /// Maps a byte range of the normalized text back onto the original text.
+    pub fn original_range(&self, range: Range<usize>) -> Range<usize> {
+        let first = self
+            .spans
+            .partition_point(|(offset, _)| *offset < range.start);
+        let last = self
+            .spans
+            .partition_point(|(offset, _)| *offset < range.end);
+        match (
+            self.spans.get(first),
+            last.checked_sub(1).map(|i| &self.spans[i]),
+        ) {
+            (Some((_, start)), Some((_, end))) if first < last => start.start..end.end,
+            _ => 0..0,
+        }
+    }
+
+    /// Maps a byte range of the original text onto the normalized text: the
+    /// chars originating from it, or an empty range where they were dropped.
+    pub fn normalized_range(&self, range: Range<usize>) -> Range<usize> {
+        let first = self
+            .spans
+            .partition_point(|(_, origin)| origin.end <= range.start);
+        let last = self
+            .spans
+            .partition_point(|(_, origin)| origin.start < range.end);
+        let offset = |index: usize| {
+            self.spans
+                .get(index)
+                .map_or(self.text.len(), |(offset, _)| *offset)
+        };
+        offset(first)..offset(last.max(first))
+    }
+
+    /// Extends the origin of the last char to also cover `origin`, used when
+    /// a char is dropped so it stays attached to the char before it.
+    fn absorb(&mut self, origin: Range<usize>) {
+        if let Some((_, last)) = self.spans.last_mut() {
+            last.end = last.end.max(origin.end);
+        }
+    }
+
+    /// Removes HTML tags and decodes character entities, so markup neither
+    /// becomes searchable nor splits the words it falls inside. Tags starting
+    /// a block (<br>, <p>, <h2>, ...) are read as a space; a decoded entity
+    /// originates from the whole entity.
+    pub fn strip_html(&self) -> NormalizedText {
+        let chars: Vec<(char, Range<usize>)> = self.chars().collect();
+        let mut stripped = NormalizedText::default();
+        let mut i = 0;
+        while i < chars.len() {
+            let (c, origin) = chars[i].clone();
+            if let Some(len) = tag_len(&chars[i..]) {
+                if BLOCK_TAGS.contains(&tag_name(&chars[i..i + len]).as_str()) {
+                    stripped.push(' ', origin.start..chars[i + len - 1].1.end);
+                }
+                i += len;
+            } else if let Some((decoded, len)) = entity(&chars[i..]) {
+                stripped.push(decoded, origin.start..chars[i + len - 1].1.end);
+                i += len;
+            } else {
+                stripped.push(c, origin);
+                i += 1;
+            }
+        }
+        stripped
+    }
+
+    /// The normalization documents and queries share, so text that looks the
+    /// same is searched the same: invisible characters are dropped, marks
+    /// folded away and quotes read as abbreviation marks.
+    pub fn fold(&self) -> NormalizedText {
+        self.drop_invisible().fold_marks().fold_quotes()
+    }
+
+    /// Drops the invisible characters, see `is_invisible`, keeping the words
+    /// around them whole.
+    fn drop_invisible(&self) -> NormalizedText {
+        let mut visible = NormalizedText::default();
+        for (c, origin) in self.chars() {
+            if is_invisible(c) {
+                visible.absorb(origin);
+            } else {
+                visible.push(c, origin);
+            }
+        }
+        visible
+    }
+
+    /// Decomposes every char by compatibility (NFKD) and drops the combining
+    /// marks, which removes niqqud, teamim, meteg, rafe and dagesh from Hebrew
+    /// text and folds presentation forms (שׁ, wide letters, the ﭏ ligature),
+    /// non-breaking spaces and full-width digits to their plain forms.
+    /// Compatibility rather than canonical decomposition is used because such
+    /// forms look like, and should find, the plain letters.
+    fn fold_marks(&self) -> NormalizedText {
+        let mut folded = NormalizedText::default();
+        for (c, origin) in self.chars() {
+            decompose_compatible(c, |d| {
+                if is_combining_mark(d) {
+                    folded.absorb(origin.clone());
+                } else {
+                    folded.push(d, origin.clone());
+                }
+            });
+        }
+        folded
+    }
+
+    /// Rewrites quotes used as abbreviation marks to the proper gershayim and
+    /// geresh: a double quote (or two apostrophes) between Hebrew letters
+    /// becomes ״, and an apostrophe after a Hebrew letter becomes ׳ when it
+    /// is followed by a letter or ends a short word. Other quotes are left
+    /// alone, so they keep working as phrase delimiters in queries.
+    fn fold_quotes(&self) -> NormalizedText {
+        let chars: Vec<(char, Range<usize>)> = self.chars().collect();
+        let mut folded = NormalizedText::default();
+        let mut letters = 0;
+        let mut i = 0;
+        while i < chars.len() {
+            let (c, origin) = chars[i].clone();
+            let after_letter = letters > 0;
+            let is_pair = c == '\'' && chars.get(i + 1).is_some_and(|(n, _)| *n == '\'');
+            let quote_len = if is_pair { 2 } else { 1 };
+            let before_letter = chars
+                .get(i + quote_len)
+                .is_some_and(|(n, _)| is_hebrew_letter(*n));
+            if after_letter && before_letter && (is_pair || GERSHAYIM_LIKE.contains(&c)) {
+                folded.push(GERSHAYIM, origin.start..chars[i + quote_len - 1].1.end);
+                i += quote_len;
+                continue;
+            }
+            let ends_word = !chars.get(i + 1).is_some_and(|(n, _)| n.is_alphanumeric());
+            if after_letter
+                && GERESH_LIKE.contains(&c)
+                && (before_letter || (ends_word && letters <= MAX_GERESH_WORD_LEN))
+            {
+                folded.push(GERESH, origin);
+            } else {
+                folded.push(c, origin);
+                letters = match c {
+                    c if is_hebrew_letter(c) => letters + 1,
+                    GERESH | GERSHAYIM => letters,
+                    _ => 0,
+                };
+            }
+            i += 1;
+        }
+        folded
+    }
+
+    /// Joins words spelled out letter by letter with hyphens, the way the
+    /// Tetragrammaton is written as "י-ה-ו-ה".
+    pub fn join_spelled_out(&self) -> NormalizedText {
+        let chars: Vec<(char, Range<usize>)> = self.chars().collect();
+        let mut joined = NormalizedText::default();
+        let mut i = 0;
+        while i < chars.len() {
+            let starts_word = i == 0 || !chars[i - 1].0.is_alphanumeric();
+            let letters = spelled_out_letters(&chars[i..]);
+            if starts_word && letters >= MIN_SPELLED_OUT_LETTERS {
+                for (c, origin) in &chars[i..i + 2 * letters - 1] {
+                    if HYPHENS.contains(c) {
+                        joined.absorb(origin.clone());
+                    } else {
+                        joined.push(*c, origin.clone());
+                    }
+                }
+                i += 2 * letters - 1;
+            } else {
+                joined.push(chars[i].0, chars[i].1.clone());
+                i += 1;
+            }
+        }
+        joined
+    }
+}
+
+/// The length in chars of the HTML tag at the start of `chars`, if one starts
+/// there.
+fn tag_len(chars: &[(char, Range<usize>)]) -> Option<usize> {
+    let opens_tag = chars.first().is_some_and(|(c, _)| *c == '<')
+        && chars
+            .get(1)
+            .is_some_and(|(c, _)| c.is_ascii_alphabetic() || matches!(c, '/' | '!'));
+    if !opens_tag {
+        return None;
+    }
+    chars.iter().position(|(c, _)| *c == '>').map(|end| end + 1)
+}
+
+/// The lowercased name of `tag`: "h2" for both "<h2 class=x>" and "</h2>".
+fn tag_name(tag: &[(char, Range<usize>)]) -> String {
+    tag.iter()
+        .skip(1)
+        .map(|(c, _)| *c)
+        .skip_while(|c| *c == '/')
+        .take_while(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// The char encoded by the character entity (`&quot;`, `&#1488;`) at the
+/// start of `chars`, with the length of the entity in chars.
+fn entity(chars: &[(char, Range<usize>)]) -> Option<(char, usize)> {
+    if chars.first().is_none_or(|(c, _)| *c != '&') {
+        return None;
+    }
+    let end = chars
+        .iter()
+        .take(MAX_ENTITY_LEN + 2)
+        .position(|(c, _)| *c == ';')?;
+    let entity: String = chars[..=end].iter().map(|(c, _)| *c).collect();
+    let decoded = htmlescape::decode_html(&entity).ok()?;
+    let mut decoded = decoded.chars();
+    match (decoded.next(), decoded.next()) {
+        (Some(c), None) => Some((c, end + 1)),
+        _ => None,
+    }
+}
+
+/// The number of letters in a run of single Hebrew letters joined by hyphens
+/// at the start of `chars`, or 0 if the run is part of a longer word.
+fn spelled_out_letters(chars: &[(char, Range<usize>)]) -> usize {
+    let mut letters = 0;
+    while chars
+        .get(2 * letters)
+        .is_some_and(|(c, _)| is_hebrew_letter(*c))
+    {
+        letters += 1;
+        let next = chars.get(2 * letters - 1).map(|(c, _)| *c);
+        let after = chars.get(2 * letters).map(|(c, _)| *c);
+        match (next, after) {
+            (Some(hyphen), Some(letter))
+                if HYPHENS.contains(&hyphen) && is_hebrew_letter(letter) => {}
+            (Some(c), _) if c.is_alphanumeric() => return 0,
+            _ => return letters,
+        }
+    }
+    letters
+}
@@ -0,0 +1,161 @@
+use super::gematria::letter_value;
+use super::normalize::{is_hebrew_letter, GERESH, GERSHAYIM};
+use crate::api::search_engine::GematriaMethod;
+
+/// Marks the numeric form of numbers, under which Hebrew numerals and
+/// numbers written in digits are indexed alike.
+pub const NUMBER_MARK: char = '#';
+
+/// Digits numeric forms are padded to, so they sort in numeric order and
+/// range queries compare them as numbers. Longer numbers have no numeric form.
+const NUMBER_WIDTH: usize = 6;
+
+const FINAL_LETTERS: &[char] = &['ך', 'ם', 'ן', 'ף', 'ץ'];
+
+/// The letter that with a geresh alone stands for the divine name (ה׳)
+/// rather than for its value.
+const DIVINE_NAME_LETTER: &str = "ה";
+
+/// The value of a number as written in text: digits, or a Hebrew numeral
+/// marked as one, with a gershayim before its last letter (כ״א, קכ״ג) or a
+/// geresh after a single letter (ז׳) other than that of the divine name ה׳,
+/// possibly after a thousands letter and a geresh (ה׳תשפ״ו). Unmarked letters
+/// are left alone, being words far more often than numbers.
+pub fn value(word: &str) -> Option<u32> {
+    digits_value(word).or_else(|| marked_value(word))
+}
+
+/// The value of a number written in digits.
+fn digits_value(word: &str) -> Option<u32> {
+    if word.is_empty() || word.len() > NUMBER_WIDTH || !word.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    word.parse().ok()
+}
+
+fn marked_value(word: &str) -> Option<u32> {
+    let (thousands, units) = match word.split_once(GERESH) {
+        Some((thousands, units)) if !units.is_empty() => {
+            (letters_value(thousands).filter(|value| *value < 10)?, units)
+        }
+        _ => (0, word),
+    };
+    let letters = match units.split_once(GERSHAYIM) {
+        Some((head, last)) if last.chars().count() == 1 => format!("{head}{last}"),
+        Some(_) => return None,
+        None => units
+            .strip_suffix(GERESH)
+            .filter(|letter| letter.chars().count() == 1 && *letter != DIVINE_NAME_LETTER)?
+            .to_string(),
+    };
+    Some(thousands * 1000 + letters_value(&letters)?)
+}
+
+/// The value of a Hebrew numeral, marked or not: "קכג" and "קכ״ג" both give
+/// 123. Returns `None` for anything else, including letters out of numeral
+/// order such as "רש״י".
+pub fn parse(text: &str) -> Option<u32> {
+    value(text).or_else(|| letters_value(text))
+}
+
+/// The value of unmarked numeral letters: hundreds, tens and units in
+/// descending order, with ט״ו and ט״ז for 15 and 16 instead of the letters of
+/// the divine name.
+fn letters_value(letters: &str) -> Option<u32> {
+    if letters.is_empty()
+        || !letters.chars().all(is_hebrew_letter)
+        || letters.contains(FINAL_LETTERS)
+    {
+        return None;
+    }
+    let values: Vec<u32> = letters
+        .chars()
+        .map(|c| letter_value(c, GematriaMethod::Standard))
+        .collect();
+    let (body, teen) = match values[..] {
+        [ref body @ .., 9, units @ (6 | 7)] => (body, 9 + units),
+        [.., 10, 5 | 6] => return None,
+        _ => (&values[..], 0),
+    };
+    let count = |range: std::ops::Range<u32>| body.iter().filter(|v| range.contains(v)).count();
+    let descending = body.windows(2).all(|pair| pair[0] >= pair[1]);
+    let single = count(1..10) <= 1 && count(10..100) <= 1 && count(100..400) <= 1;
+    let teen_last = teen == 0 || body.iter().all(|v| *v >= 100);
+    (descending && single && teen_last).then(|| body.iter().sum::<u32>() + teen)
+}
+
+/// The form numbers are indexed and looked up in, e.g. "#000021".
+pub fn form(value: u32) -> String {
+    format!("{NUMBER_MARK}{value:0NUMBER_WIDTH$}")
+}
+
+/// Rewrites the bounds of range queries given without a field to digits and
+/// points them at `field`, which the query parser requires, so "[י TO כ]"
+/// reads as "text:[10 TO 20]" and is compared by value.
+pub fn rewrite_ranges(query: &str, field: &str) -> String {
+    let mut rewritten = String::new();
+    let mut rest = query;
+    while let Some(start) = rest.find(['[', '{']) {
+        let Some(end) = rest[start..].find([']', '}']).map(|end| start + end) else {
+            break;
+        };
+        let (before, range) = rest.split_at(start);
+        rewritten.push_str(before);
+        if before.is_empty() || before.ends_with([' ', '(', '+', '-']) {
+            rewritten.push_str(field);
+            rewritten.push(':');
+        }
+        rewritten.push_str(&range[..1]);
+        let bounds: Vec<String> = rest[start + 1..end]
+            .split_whitespace()
+            .map(|bound| match parse(bound) {
+                Some(value) if digits_value(bound).is_none() => value.to_string(),
+                _ => bound.to_string(),
+            })
+            .collect();
+        rewritten.push_str(&bounds.join(" "));
+        rest = &rest[end..];
+    }
+    rewritten.push_str(rest);
+    rewritten
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{letters_value, value};
+
+    #[test]
+    fn teens_avoid_the_divine_name() {
+        assert_eq!(letters_value("טו"), Some(15));
+        assert_eq!(letters_value("טז"), Some(16));
+        assert_eq!(letters_value("קטו"), Some(115));
+        assert_eq!(letters_value("יה"), None);
+        assert_eq!(letters_value("יו"), None);
+    }
+
+    #[test]
+    fn letters_go_in_descending_order() {
+        assert_eq!(letters_value("קכג"), Some(123));
+        assert_eq!(letters_value("תתק"), Some(900));
+        assert_eq!(letters_value("כק"), None);
+        assert_eq!(letters_value("רשי"), None);
+        assert_eq!(letters_value("יא"), Some(11));
+        assert_eq!(letters_value("אי"), None);
+        assert_eq!(letters_value("ככ"), None);
+    }
+
+    #[test]
+    fn final_letters_are_not_numerals() {
+        assert_eq!(letters_value("תך"), None);
+    }
+
+    #[test]
+    fn marked_numerals() {
+        assert_eq!(value("כ״א"), Some(21));
+        assert_eq!(value("ז׳"), Some(7));
+        assert_eq!(value("ה׳תשפ״ו"), Some(5786));
+        assert_eq!(value("ה׳"), None);
+        assert_eq!(value("כא"), None);
+        assert_eq!(value("021"), Some(21));
+    }
+}
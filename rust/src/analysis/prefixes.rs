@@ -0,0 +1,65 @@
+/// Marks a token as a prefix-insensitive form. Indexed words get one such
+/// token for themselves and one for every stem left after stripping their
+/// prefix letters, so a marked query term matches the word with or without
+/// prefixes while unmarked terms keep matching exact spellings only.
+pub const STEM_MARK: char = '*';
+
+/// The prefix letters (אותיות השימוש) that attach to the start of a word.
+const PREFIX_LETTERS: &[char] = &['ו', 'ה', 'ב', 'כ', 'ל', 'מ', 'ש'];
+
+/// Longest prefix combination stripped, e.g. "וכשה".
+pub const MAX_PREFIX_LEN: usize = 4;
+
+/// Shortest stem kept, in letters, so "של" does not yield "ל".
+const MIN_STEM_LEN: usize = 2;
+
+/// Returns the stems of `word` obtained by stripping one or more leading
+/// prefix letters: "ושבתורה" gives "שבתורה", "בתורה" and "תורה".
+pub fn stems(word: &str) -> Vec<&str> {
+    let letters = word.chars().count();
+    word.char_indices()
+        .skip(1)
+        .take(MAX_PREFIX_LEN)
+        .take_while(|(offset, _)| word[..*offset].chars().all(is_prefix_letter))
+        .enumerate()
+        .take_while(|(stripped, _)| letters - (stripped + 1) >= MIN_STEM_LEN)
+        .map(|(_, (offset, _))| &word[offset..])
+        .collect()
+}
+
+pub fn is_prefix_letter(c: char) -> bool {
+    PREFIX_LETTERS.contains(&c)
+}
+
+/// The prefix-insensitive form of `word`, as looked up by queries.
+pub fn marked(word: &str) -> String {
+    format!("{STEM_MARK}{word}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stems;
+
+    #[test]
+    fn every_prefix_combination_is_stripped() {
+        assert_eq!(stems("ושבתורה"), ["שבתורה", "בתורה", "תורה"]);
+        assert_eq!(stems("וכשהבית"), ["כשהבית", "שהבית", "הבית", "בית"]);
+    }
+
+    #[test]
+    fn stripping_stops_at_the_first_other_letter() {
+        assert!(stems("תורה").is_empty());
+        assert_eq!(stems("בראשית"), ["ראשית"]);
+    }
+
+    #[test]
+    fn short_stems_are_not_kept() {
+        assert!(stems("של").is_empty());
+        assert_eq!(stems("משה"), ["שה"]);
+    }
+
+    #[test]
+    fn at_most_four_prefix_letters_are_stripped() {
+        assert_eq!(stems("ובבבבית"), ["בבבבית", "בבבית", "בבית", "בית"]);
+    }
+}
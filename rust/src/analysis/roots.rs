@@ -0,0 +1,167 @@
+use super::normalize::is_hebrew_letter;
+use super::prefixes;
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+use std::sync::RwLock;
+
+/// Known roots, when a lexicon was loaded with [`load_lexicon`]. Candidate
+/// roots outside it are then dropped.
+static LEXICON: Lazy<RwLock<Option<HashSet<String>>>> = Lazy::new(Default::default);
+
+/// Inflectional suffixes stripped from the end of a word: pronominal,
+/// plural, feminine and past-tense endings.
+const SUFFIXES: &[&str] = &[
+    "", "ו", "ה", "ת", "י", "ך", "ם", "ן", "ים", "ות", "תי", "תם", "תן", "נו", "הו", "ני", "כם",
+    "כן", "הם", "הן", "יך", "יו", "יה", "ית", "תה", "ינו", "יהם", "יהן", "ותי", "ותם",
+];
+
+/// Noun endings that a pronominal suffix may follow, as in משמרתו.
+const NOUN_ENDINGS: &[&str] = &["", "ת", "ות"];
+
+/// Letters added in front of the root by the binyanim and the future tense,
+/// and by nouns formed with a mem.
+const FORMATIVES: &[&str] = &[
+    "", "י", "ת", "נ", "א", "מ", "ה", "הת", "ית", "תת", "נת", "את", "מת",
+];
+
+/// Final letter forms, written as their regular forms in roots.
+const FINAL_LETTERS: &[(char, char)] =
+    &[('ך', 'כ'), ('ם', 'מ'), ('ן', 'נ'), ('ף', 'פ'), ('ץ', 'צ')];
+
+/// Root letters a weak root loses in some inflections: the middle vav or
+/// yud of hollow roots (קם from קום), the final he of ל"ה roots (בנו from
+/// בנה), and the initial nun or yud of פ"נ and פ"י roots (תפל from נפל).
+fn weak_roots(a: char, b: char) -> [[char; 3]; 5] {
+    [
+        [a, 'ו', b],
+        [a, 'י', b],
+        [a, b, 'ה'],
+        ['נ', a, b],
+        ['י', a, b],
+    ]
+}
+
+/// Candidate roots (שורשים) of `word`, found by stripping prefix letters,
+/// suffixes, noun endings and binyan formatives in every combination and
+/// keeping the three-letter remainders. Without a lexicon this overgenerates
+/// on purpose: a root query should rather find a stray extra word than miss
+/// an inflection.
+pub fn candidates(word: &str) -> Vec<String> {
+    let lexicon = LEXICON.read().unwrap();
+    let mut roots = Vec::new();
+    let stems = std::iter::once(word).chain(prefixes::stems(word));
+    for stem in stems {
+        let stems = SUFFIXES
+            .iter()
+            .filter_map(|suffix| stem.strip_suffix(suffix));
+        let stems = stems.flat_map(|stem| {
+            NOUN_ENDINGS
+                .iter()
+                .filter_map(move |ending| stem.strip_suffix(ending))
+        });
+        for stem in stems {
+            for formative in FORMATIVES {
+                let Some(remainder) = stem.strip_prefix(formative) else {
+                    continue;
+                };
+                for root in reduce(remainder) {
+                    let known = lexicon.as_ref().is_none_or(|roots| roots.contains(&root));
+                    if known && !roots.contains(&root) {
+                        roots.push(root);
+                    }
+                }
+            }
+        }
+    }
+    roots
+}
+
+/// The roots a stem stripped of its affixes may come from: its letters less
+/// inner matres lectionis, when three remain, or the weak roots of a
+/// two-letter remainder.
+fn reduce(stem: &str) -> Vec<String> {
+    if !stem.chars().all(is_hebrew_letter) {
+        return Vec::new();
+    }
+    let mut letters = stem.chars().map(regular_form);
+    let consonants: Vec<char> = letters
+        .next()
+        .into_iter()
+        .chain(letters.filter(|c| !matches!(c, 'ו' | 'י')))
+        .collect();
+    match consonants[..] {
+        [_, _, _] => vec![consonants.iter().collect()],
+        [a, b] => weak_roots(a, b)
+            .iter()
+            .map(|root| root.iter().collect())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// `word` with final letter forms replaced by regular ones, the form in
+/// which roots are indexed and looked up.
+pub fn normalize(word: &str) -> String {
+    word.chars().map(regular_form).collect()
+}
+
+fn regular_form(c: char) -> char {
+    FINAL_LETTERS
+        .iter()
+        .find(|(last, _)| *last == c)
+        .map_or(c, |(_, regular)| *regular)
+}
+
+/// Restricts candidate roots to those listed in the file at `path`, one root
+/// per line, and returns the number of roots read. Only documents indexed
+/// afterwards are affected.
+pub fn load_lexicon(path: &str) -> Result<usize> {
+    let roots: HashSet<String> = std::fs::read_to_string(path)?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(normalize)
+        .collect();
+    let len = roots.len();
+    *LEXICON.write().unwrap() = Some(roots);
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{candidates, normalize};
+
+    fn has_root(word: &str, root: &str) -> bool {
+        candidates(word).iter().any(|candidate| candidate == root)
+    }
+
+    #[test]
+    fn affixes_are_stripped() {
+        let words = ["שמר", "שומר", "נשמרו", "משמרת", "וישמרו", "שמרתם", "משמרתו"];
+        for word in words {
+            assert!(has_root(word, "שמר"), "{word}: {:?}", candidates(word));
+        }
+    }
+
+    #[test]
+    fn weak_roots_are_restored() {
+        assert!(has_root("שבו", "שוב"));
+        assert!(has_root("בנו", "בנה"));
+        assert!(has_root("תפל", "נפל"));
+        assert!(has_root("ישב", "ישב"));
+    }
+
+    #[test]
+    fn roots_are_written_without_final_letters() {
+        assert!(has_root("מלך", "מלכ"));
+        assert!(!has_root("מלך", "מלך"));
+        assert_eq!(normalize("מלך"), "מלכ");
+    }
+
+    #[test]
+    fn words_without_a_three_letter_remainder_have_no_root() {
+        assert!(candidates("ב").is_empty());
+        assert!(candidates("abc").is_empty());
+    }
+}
@@ -0,0 +1,24 @@
+/// The matres lectionis (אמות קריאה) that plene spelling adds and defective
+/// spelling leaves out.
+const MATRES_LECTIONIS: &[char] = &['ו', 'י'];
+
+/// Shortest skeleton kept, in letters, so "לו" and "כי" are not reduced to
+/// a single letter.
+const MIN_SKELETON_LEN: usize = 2;
+
+/// The consonantal skeleton of `word`: the word without vav and yud, except
+/// for a leading one, which is a consonant. Plene and defective spellings
+/// share a skeleton: "שלום" and "שלם" give "שלם", "דויד" and "דוד" give "דד".
+pub fn skeleton(word: &str) -> String {
+    let mut letters = word.chars();
+    let skeleton: String = letters
+        .next()
+        .into_iter()
+        .chain(letters.filter(|c| !MATRES_LECTIONIS.contains(c)))
+        .collect();
+    if skeleton.chars().count() >= MIN_SKELETON_LEN {
+        skeleton
+    } else {
+        word.to_string()
+    }
+}
@@ -0,0 +1,58 @@
+use super::HebrewTokenizer;
+use crate::api::search_engine::MatchOptions;
+
+/// Strings that look alike on screen, or are meant to be read alike, each
+/// group starting with its plain spelling.
+const CORPUS: &[&[&str]] = &[
+    // directional marks, byte order mark, zero-width joiner, soft hyphen
+    &[
+        "שלום עולם",
+        "\u{200F}שלום\u{200F} עולם",
+        "\u{FEFF}שלום עולם",
+        "\u{202B}שלום עולם\u{202C}",
+        "של\u{200D}ום עולם",
+        "של\u{200B}ום עולם",
+        "של\u{00AD}ום עולם",
+        "שלום\u{00A0}עולם",
+    ],
+    // presentation forms and niqqud
+    &[
+        "שבת",
+        "\u{FB2A}בת",
+        "ש\u{05C1}בת",
+        "שַׁבָּת",
+        "\u{FB2A}\u{FB31}ת",
+    ],
+    &["אל", "\u{FB4F}"],
+    &["אחד", "\u{FB21}חד"],
+    // maqaf between words
+    &["בית המקדש", "בית\u{05BE}המקדש"],
+    // quotes and marks inside abbreviations
+    &["רש״י", "רש\"י", "רש''י", "רש\u{200F}\"י", "רש\u{201D}י"],
+    &["21", "\u{FF12}\u{FF11}"],
+];
+
+fn tokens(tokenizer: &HebrewTokenizer, text: &str) -> Vec<String> {
+    tokenizer
+        .tokenize(text)
+        .into_iter()
+        .map(|token| token.text)
+        .collect()
+}
+
+#[test]
+fn visually_identical_strings_match() {
+    let tokenizers = [
+        HebrewTokenizer::for_index(),
+        HebrewTokenizer::for_query(MatchOptions::default()),
+        HebrewTokenizer::for_index().skeleton(),
+    ];
+    for group in CORPUS {
+        for tokenizer in &tokenizers {
+            let plain = tokens(tokenizer, group[0]);
+            for variant in &group[1..] {
+                assert_eq!(tokens(tokenizer, variant), plain, "{variant:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use super::divine;
+use super::normalize::{NormalizedText, GERESH, GERSHAYIM};
+use super::numerals;
+use super::prefixes;
+use super::roots;
+use super::spelling;
+use crate::api::search_engine::MatchOptions;
+use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
+
+/// Splits Hebrew (and any other) text into words after folding away niqqud
+/// and cantillation, so vocalized and plain spellings produce the same tokens.
+/// Abbreviations written with gershayim or geresh (or quotes standing in for
+/// them) stay single words. Documents are read as HTML, without their markup.
+/// Token offsets always point into the original, unnormalized text.
+#[derive(Clone)]
+pub struct HebrewTokenizer {
+    mode: Mode,
+    forms: Forms,
+}
+
+#[derive(Clone)]
+enum Mode {
+    /// Documents: every searchable form of each word, at the word's position.
+    Index,
+    /// Queries: the single form of each word selected by the match options.
+    Query(MatchOptions),
+}
+
+/// What a word is turned into.
+#[derive(Clone, Copy)]
+enum Forms {
+    /// The word as spelled.
+    Spelling,
+    /// Its consonantal skeleton, see `spelling::skeleton`.
+    Skeleton,
+    /// Its candidate roots, see `roots::candidates`.
+    Roots,
+}
+
+pub struct HebrewTokenStream {
+    tokens: std::vec::IntoIter<Token>,
+    token: Token,
+}
+
+impl HebrewTokenizer {
+    pub fn for_index() -> Self {
+        HebrewTokenizer {
+            mode: Mode::Index,
+            forms: Forms::Spelling,
+        }
+    }
+
+    pub fn for_query(options: MatchOptions) -> Self {
+        HebrewTokenizer {
+            mode: Mode::Query(options),
+            forms: Forms::Spelling,
+        }
+    }
+
+    /// Produces the consonantal skeleton of every form instead of its
+    /// spelling, for matching plene and defective spellings.
+    pub fn skeleton(self) -> Self {
+        HebrewTokenizer {
+            forms: Forms::Skeleton,
+            ..self
+        }
+    }
+
+    /// Produces the candidate roots of every word instead of the word. Query
+    /// words are taken to be roots already.
+    pub fn roots(self) -> Self {
+        HebrewTokenizer {
+            forms: Forms::Roots,
+            ..self
+        }
+    }
+
+    pub fn tokenize(&self, text: &str) -> Vec<Token> {
+        let text = match self.mode {
+            Mode::Index => NormalizedText::new(text).strip_html(),
+            Mode::Query(_) => NormalizedText::new(text),
+        };
+        let normalized = text.fold().join_spelled_out();
+        let words = split_words(&normalized);
+        match &self.mode {
+            Mode::Index => words
+                .iter()
+                .flat_map(|word| {
+                    let mut forms = self.index_forms(&searchable_form(word));
+                    if let Some(name) = self.divine_name(word) {
+                        let name_forms = self.index_forms(&name);
+                        forms.extend(name_forms.into_iter().map(divine_marked));
+                    }
+                    if let Some(number) = self.number(word, numerals::value) {
+                        forms.push(number);
+                    }
+                    forms
+                })
+                .collect(),
+            Mode::Query(options) => words
+                .iter()
+                .map(|word| {
+                    if let Some(number) = self.number(word, numerals::value) {
+                        return number;
+                    }
+                    match self.divine_name(word) {
+                        Some(name) if options.divine_names => {
+                            divine_marked(self.query_form(&name, options))
+                        }
+                        _ => self.query_form(&searchable_form(word), options),
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// The divine name `word` spells, in its common spelling. Divine names
+    /// are only told apart when indexing spellings.
+    fn divine_name(&self, word: &Token) -> Option<Token> {
+        match self.forms {
+            Forms::Spelling => divine::canonical(&word.text).map(|name| with_text(word, name)),
+            _ => None,
+        }
+    }
+
+    /// The numeric form of `word`, if `value` reads it as a number. Roots
+    /// have no numbers.
+    fn number(&self, word: &Token, value: fn(&str) -> Option<u32>) -> Option<Token> {
+        match self.forms {
+            Forms::Roots => None,
+            _ => value(&word.text).map(|number| with_text(word, numerals::form(number))),
+        }
+    }
+
+    fn index_forms(&self, word: &Token) -> Vec<Token> {
+        if let Forms::Roots = self.forms {
+            return roots::candidates(&word.text)
+                .into_iter()
+                .map(|root| with_text(word, root))
+                .collect();
+        }
+        let spelled = self.spell(&word.text);
+        let marked = prefixes::marked(&spelled);
+        let mut forms = vec![with_text(word, spelled), with_text(word, marked)];
+        for stem in prefixes::stems(&word.text) {
+            forms.push(with_text(word, prefixes::marked(&self.spell(stem))));
+        }
+        forms
+    }
+
+    fn query_form(&self, word: &Token, options: &MatchOptions) -> Token {
+        if let Forms::Roots = self.forms {
+            return with_text(word, roots::normalize(&word.text));
+        }
+        let spelled = self.spell(&word.text);
+        if options.prefix_insensitive {
+            with_text(word, prefixes::marked(&spelled))
+        } else {
+            with_text(word, spelled)
+        }
+    }
+
+    fn spell(&self, word: &str) -> String {
+        match self.forms {
+            Forms::Skeleton => spelling::skeleton(word),
+            _ => word.to_string(),
+        }
+    }
+}
+
+/// The spelling a word is indexed and looked up under. Abbreviation marks
+/// are dropped so "רמב״ם" and "רמבם" match each other, unless that would
+/// leave a single letter, as in "ר׳".
+fn searchable_form(word: &Token) -> Token {
+    let stripped: String = word
+        .text
+        .chars()
+        .filter(|c| !matches!(*c, GERESH | GERSHAYIM))
+        .collect();
+    if stripped.chars().count() >= 2 {
+        with_text(word, stripped)
+    } else {
+        word.clone()
+    }
+}
+
+fn divine_marked(token: Token) -> Token {
+    Token {
+        text: divine::marked(&token.text),
+        ..token
+    }
+}
+
+fn with_text(token: &Token, text: String) -> Token {
+    Token {
+        text,
+        ..token.clone()
+    }
+}
+
+pub(super) fn split_words(normalized: &NormalizedText) -> Vec<Token> {
+    let text = normalized.as_str();
+    let mut tokens = Vec::new();
+    let mut word_start = None;
+    for (offset, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (word_start, is_word_char(c, word_start.is_some())) {
+            (None, true) => word_start = Some(offset),
+            (Some(start), false) => {
+                let word = text[start..offset].trim_end_matches(GERSHAYIM);
+                let origin = normalized.original_range(start..start + word.len());
+                tokens.push(Token {
+                    offset_from: origin.start,
+                    offset_to: origin.end,
+                    position: tokens.len(),
+                    text: word.to_string(),
+                    position_length: 1,
+                });
+                word_start = None;
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
+
+/// Letters and digits make up words; a geresh or gershayim continues a word
+/// that has already started. The maqaf, like any other punctuation, separates
+/// words.
+fn is_word_char(c: char, in_word: bool) -> bool {
+    c.is_alphanumeric() || (in_word && matches!(c, GERESH | GERSHAYIM))
+}
+
+impl Tokenizer for HebrewTokenizer {
+    type TokenStream<'a> = HebrewTokenStream;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> HebrewTokenStream {
+        HebrewTokenStream {
+            tokens: self.tokenize(text).into_iter(),
+            token: Token::default(),
+        }
+    }
+}
+
+impl TokenStream for HebrewTokenStream {
+    fn advance(&mut self) -> bool {
+        match self.tokens.next() {
+            Some(token) => {
+                self.token = token;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
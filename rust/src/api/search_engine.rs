@@ -2,51 +2,588 @@
 pub fn test_bindings(name: String) -> String {
     format!("Hello, {name}!")
 }
+use crate::analysis;
 use crate::frb_generated::StreamSink;
-use anyhow::{Error, Result};
-use futures::stream::{Stream, StreamExt};
+use crate::fuzzy;
+use crate::snippet;
+use crate::value_counts::ValueCounts;
 use log::debug;
-use serde_json::{json, Value};
-use std::borrow::{Borrow, BorrowMut};
-use std::collections::HashMap;
-use std::pin::Pin;
-use std::sync::{Arc, Mutex};
-use tantivy::collector::TopDocs;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::{Bound, Range};
+use tantivy::collector::{Count, DocSetCollector, TopDocs};
 use tantivy::directory::MmapDirectory;
-use tantivy::index::Index;
-use tantivy::query::{self, BooleanQuery, Occur, QueryParser, TermQuery, TermSetQuery};
-use tantivy::query::{PhraseQuery, Query};
+use tantivy::index::{Index, SegmentId};
+use tantivy::query::Query;
+use tantivy::query::{
+    AllQuery, BooleanQuery, BoostQuery, EmptyQuery, Occur, PhraseQuery, QueryParser, RangeQuery,
+    TermQuery, TermSetQuery,
+};
+use tantivy::query_grammar::{
+    self, parse_query_lenient, Delimiter, UserInputAst, UserInputBound, UserInputLeaf,
+    UserInputLiteral,
+};
+use tantivy::schema::*;
+use tantivy::tokenizer::{TextAnalyzer, TokenizerManager};
 use tantivy::{
-    doc, tokenizer, DocAddress, IndexReader, IndexWriter, Order, ReloadPolicy, Score, Searcher,
-    SnippetGenerator,
+    doc, DocAddress, DocId, IndexReader, IndexWriter, Score, Searcher, SegmentReader, TantivyError,
+};
+
+/// Weight of a match on the consonantal skeleton relative to a match on the
+/// exact spelling.
+const SKELETON_BOOST: Score = 0.5;
+
+/// Most query words a proximity search takes, which bounds the number of
+/// phrase queries an ordered search expands to.
+const MAX_PROXIMITY_WORDS: usize = 6;
+
+/// Most phrase queries an ordered search of more than two words expands to,
+/// which bounds the distance allowed between its words.
+const MAX_PROXIMITY_PHRASES: u64 = 1024;
+
+/// Typos a fuzzy word of a query string tolerates.
+const FUZZY_DISTANCE: u8 = 1;
+
+/// Most typos a fuzzy word of a structured query tolerates.
+const MAX_FUZZINESS: u8 = 2;
+
+/// Words of a phrase, which have to follow one another.
+const EXACT_PHRASE: Proximity = Proximity {
+    max_distance: 0,
+    ordered: true,
 };
-use tantivy::{schema::*, Directory};
 
 #[derive(Clone)]
 pub struct SearchResult {
     pub title: String,
+    /// Plain text around the words matched, or the whole text of the
+    /// segment when there is nothing to highlight.
     pub text: String,
+    /// The matched parts of `text`, in order.
+    pub highlights: Vec<Highlight>,
+    /// `text` as HTML with the highlights marked, when the snippet options
+    /// ask for it.
+    pub html: Option<String>,
     pub id: u64,
     pub segment: u64,
     pub is_pdf: bool,
     pub file_path: String,
+    /// Where the result stands in the order of the search, for fetching the
+    /// results after it.
+    pub cursor: SearchCursor,
+    /// The segments of the book just before the result, in order, when the
+    /// search asks for context.
+    pub preceding: Vec<NeighborSegment>,
+    /// The segments of the book just after the result, in order.
+    pub following: Vec<NeighborSegment>,
+}
+
+/// A segment next to a result in its book, with its whole text.
+#[derive(Clone, Debug)]
+pub struct NeighborSegment {
+    pub segment: u64,
+    pub text: String,
+}
+
+/// A matched part of the text of a [`SearchResult`].
+#[derive(Clone, Debug)]
+pub struct Highlight {
+    /// Where the part starts in the text, in bytes of UTF-8.
+    pub byte_start: u32,
+    pub byte_end: u32,
+    /// Where the part starts in the text, in characters.
+    pub char_start: u32,
+    pub char_end: u32,
+    /// The query term the part matched. For proximity searches, which
+    /// highlight a run of nearby words as one part, the terms of its words
+    /// separated by spaces.
+    pub term: String,
+}
+
+/// The matches of a search in a segment, see `highlight_in_document`.
+#[derive(Clone, Debug)]
+pub struct SegmentHighlights {
+    pub segment: u64,
+    /// The matched parts of the stored text of the segment, in order.
+    pub highlights: Vec<Highlight>,
+}
+
+/// How the snippets of results are made: up to `fragments` parts of the
+/// text around the words matched, each of at most `max_chars` characters,
+/// joined by `separator`.
+#[derive(Clone, Debug)]
+pub struct SnippetOptions {
+    pub max_chars: u32,
+    /// Most fragments in a snippet, the best scoring ones, at least 1.
+    pub fragments: u32,
+    /// Text put between fragments, such as " … ".
+    pub separator: String,
+    pub boundary: SnippetBoundary,
+    /// Markup put around the highlights in `SearchResult::html`. Without
+    /// it, results come without HTML.
+    pub html_tags: Option<HighlightTags>,
+}
+
+impl Default for SnippetOptions {
+    fn default() -> Self {
+        SnippetOptions {
+            max_chars: 400,
+            fragments: 1,
+            separator: " … ".to_string(),
+            boundary: SnippetBoundary::Word,
+            html_tags: None,
+        }
+    }
+}
+
+/// Where the fragments of a snippet start and end. Each is centered on the
+/// words it matches, with as much text before them as after.
+#[derive(Clone, Copy, Debug)]
+pub enum SnippetBoundary {
+    /// Anywhere, even within a word.
+    None,
+    /// Between words.
+    Word,
+    /// At the ends of the sentences holding the matched words, when they
+    /// fit in the fragment, otherwise between words.
+    Sentence,
+}
+
+/// Markup opening and closing a highlight, such as `<b>` and `</b>`.
+#[derive(Clone, Debug)]
+pub struct HighlightTags {
+    pub open: String,
+    pub close: String,
+}
+
+/// The results of a search, with the number of segments matching it.
+#[derive(Clone)]
+pub struct SearchResponse {
+    /// The results asked for.
+    pub hits: Vec<SearchResult>,
+    /// Segments matching the search in all the books searched, beyond
+    /// those returned.
+    pub total_count: u64,
+    /// Segments matching the search in each book, most first.
+    pub per_book_counts: Vec<BookCount>,
+    /// Segments matching the search in each category and the categories
+    /// below it, listing every category before those below it.
+    pub per_category_counts: Vec<CategoryCount>,
+}
+
+/// The number of segments of a book matching a search.
+#[derive(Clone, Debug)]
+pub struct BookCount {
+    pub title: String,
+    pub count: u64,
+}
+
+/// The number of segments in a category of the library matching a search.
+#[derive(Clone, Debug)]
+pub struct CategoryCount {
+    /// The folders of the category, separated by `/`, as in
+    /// `אוצריא/תלמוד בבלי/סדר מועד`.
+    pub category: String,
+    pub count: u64,
+}
+
+/// The position of a result in the order of a search. Passed back as `after`,
+/// the same search resumes with the results following it. The index address
+/// breaking ties between equal results stays valid until the next commit, so
+/// pages fetched in between neither skip nor repeat results.
+#[derive(Clone, Debug)]
+pub struct SearchCursor {
+    /// Relevance of the result.
+    pub score: f32,
+    pub id: u64,
+    /// Title of the book, when sorting by title.
+    pub title: String,
+    pub segment: u64,
+    /// Index segment holding the result.
+    pub segment_ord: u32,
+    /// The result within its index segment.
+    pub doc_id: u32,
+}
+
+/// The books a search looks in: all of them or those included by title or
+/// by category, less those excluded by either. Categories are paths of
+/// folders of the library, such as `אוצריא/תלמוד בבלי`, and take in the
+/// categories below them.
+#[derive(Clone, Debug, Default)]
+pub struct BookFilter {
+    /// Looks in every book not excluded, ignoring the books included.
+    pub all_books: bool,
+    pub include_titles: Vec<String>,
+    pub include_categories: Vec<String>,
+    pub exclude_titles: Vec<String>,
+    pub exclude_categories: Vec<String>,
+}
+
+/// Per-query switches controlling how the words of a query match the words
+/// of the indexed text.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MatchOptions {
+    /// Also match words carrying prefix letters (ו/ה/ב/כ/ל/מ/ש), so "תורה"
+    /// finds "והתורה" and "שבתורה".
+    pub prefix_insensitive: bool,
+    /// Also match other plene and defective spellings (כתיב מלא/חסר) of a
+    /// word, so "שלום" finds "שלם" and "דוד" finds "דויד". These matches
+    /// rank below matches of the spelling searched for.
+    pub spelling_insensitive: bool,
+    /// Read the query words as roots (שורשים) and match every inflection of
+    /// them, so "שמר" finds "שומר", "נשמרו" and "משמרת". Fuzzy query strings
+    /// cannot be read this way.
+    pub by_root: bool,
+    /// Treat the ways of writing a divine name as one word, so "ה'" also
+    /// finds "יקוק", "ידוד" and "י-ה-ו-ה", and "אלקים" finds "אלהים" and
+    /// "אלוקים". Off, each spelling only finds itself.
+    pub divine_names: bool,
+}
+
+/// Finds the words of a query near each other rather than anywhere in a
+/// segment. Its words are matched exactly: a search near each other cannot
+/// also be fuzzy.
+#[derive(Clone, Copy, Debug)]
+pub struct Proximity {
+    /// Most words allowed between a query word and the one before it. An
+    /// ordered search of more than two words rejects distances too long for
+    /// its number of words, see `MAX_PROXIMITY_PHRASES`.
+    pub max_distance: u32,
+    /// Whether the words have to appear in the order they were typed.
+    pub ordered: bool,
+}
+
+/// A query assembled from parts instead of parsed from text. Its words are
+/// always taken literally, so quotes, colons or parentheses typed by the user
+/// can never be misread as query syntax.
+#[derive(Clone, Debug)]
+pub struct SearchQuery {
+    /// Words, phrases and groups of nearby words to look for.
+    pub clauses: Vec<QueryClause>,
+    /// Restrictions on the fields of the results, all of which they meet.
+    pub filters: Vec<FieldFilter>,
+}
+
+/// A word, a phrase or a group of nearby words of a [`SearchQuery`].
+#[derive(Clone, Debug)]
+pub struct QueryClause {
+    /// The words to look for, one for a single word and several for a phrase
+    /// or a proximity group. Words are analyzed like query text, so an entry
+    /// holding several words counts as several.
+    pub words: Vec<String>,
+    pub occur: ClauseOccur,
+    /// Typos tolerated in a single word, at most 2. Phrases and proximity
+    /// groups are matched exactly.
+    pub fuzziness: u8,
+    /// How near each other several words have to be. `None` looks for them
+    /// as an exact phrase.
+    pub proximity: Option<Proximity>,
+}
+
+/// Whether results have to match a [`QueryClause`].
+#[derive(Clone, Copy, Debug)]
+pub enum ClauseOccur {
+    /// The clause may match, and results matching more such clauses rank
+    /// higher. Without required clauses, at least one has to match.
+    Should,
+    /// The clause has to match.
+    Must,
+    /// The clause must not match.
+    MustNot,
+}
+
+/// Restricts a [`SearchQuery`] to results whose `field` matches one of
+/// `values`, or to the other results when `exclude` is set.
+#[derive(Clone, Debug)]
+pub struct FieldFilter {
+    pub field: FilterField,
+    pub values: Vec<String>,
+    pub exclude: bool,
+}
+
+/// Fields a [`FieldFilter`] can restrict.
+#[derive(Clone, Copy, Debug)]
+pub enum FilterField {
+    /// Book titles, compared whole.
+    Title,
+    /// File paths, containing any word of the values.
+    FilePath,
+}
+
+/// The order results come in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Best matches first.
+    Relevance,
+    /// The order of the library, by `id`.
+    LibraryOrder,
+    /// The order of the library, from its end.
+    LibraryOrderDescending,
+    /// By book title, then by segment within the book.
+    Title,
+    /// By book title from the end of the alphabet, then by segment from the
+    /// end of the book.
+    TitleDescending,
+}
+
+/// Letter values used to compute gematria.
+#[derive(Clone, Copy, Debug)]
+pub enum GematriaMethod {
+    /// מספר הכרחי: א=1 up to ת=400, final letters counting as regular ones.
+    Standard,
+    /// מספר קטן: the standard value without its zeros, so י=1 and ק=1.
+    Small,
+    /// מספר גדול: final letters continue the count, ך=500 up to ץ=900.
+    Large,
+}
+
+/// What `search_gematria` looks for besides the value itself.
+#[derive(Clone, Copy, Debug)]
+pub struct GematriaOptions {
+    pub method: GematriaMethod,
+    /// Fewest consecutive words whose total value may match, at least 1.
+    pub min_words: u32,
+    /// Most consecutive words whose total value may match, at most 3.
+    pub max_words: u32,
+}
+
+/// How `create_search_query` interpreted a query string, for showing the
+/// user what was actually searched.
+#[derive(Clone, Debug)]
+pub struct QueryExplanation {
+    /// The query text handed to the query parser after expansion.
+    pub parsed_query: String,
+    /// Words of the query that were also searched in another form.
+    pub expansions: Vec<QueryExpansion>,
+    /// The query built, without the restriction to the chosen books. `None`
+    /// when the query is invalid.
+    pub root: Option<QueryNode>,
+    /// Why the query is invalid, if it is.
+    pub error: Option<SearchEngineError>,
+}
+
+/// A part of the query built by `create_search_query`, see `explain_query`.
+#[derive(Clone, Debug)]
+pub struct QueryNode {
+    pub kind: QueryNodeKind,
+    /// Whether results have to match this part of the group holding it.
+    pub occur: ClauseOccur,
+    /// The field looked up, e.g. "text" or "skeleton". Empty for groups.
+    pub field: String,
+    /// The query text this part was built from.
+    pub text: String,
+    /// The terms looked up, as analyzed for `field`, or the bounds of a
+    /// range.
+    pub terms: Vec<String>,
+    /// Typos tolerated by a fuzzy word, positions the words of a phrase may
+    /// be out of place in total, or words allowed between those of a
+    /// proximity group.
+    pub distance: u32,
+    /// Whether the words of a proximity group have to keep their order.
+    pub ordered: bool,
+    /// Weight of matches relative to those of the other parts.
+    pub boost: f32,
+    /// For a fuzzy word, the indexed words it matches, at most 50.
+    pub fuzzy_expansions: Vec<String>,
+    /// The parts of a group.
+    pub children: Vec<QueryNode>,
+}
+
+/// What a [`QueryNode`] looks for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueryNodeKind {
+    /// Its children, each as required by its `occur`.
+    Group,
+    /// A single word.
+    Term,
+    /// A word, allowing typos.
+    Fuzzy,
+    /// Words one after another.
+    Phrase,
+    /// Words near each other.
+    Proximity,
+    /// Values between two bounds.
+    Range,
+    /// Any of several words.
+    TermSet,
+    /// Everything having a value in the field.
+    Exists,
+    /// Everything.
+    All,
+    /// Nothing, for text without searchable words.
+    Nothing,
+}
+
+/// How the score of a result was computed, see `explain_score`.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct ScoreExplanation {
+    pub value: f32,
+    pub description: String,
+    /// The scores this one was computed from.
+    #[serde(default)]
+    pub details: Vec<ScoreExplanation>,
+    /// Remarks on the computation, e.g. terms missing from the index.
+    #[serde(default)]
+    pub context: Vec<String>,
+}
+
+/// Words of a query searched together with their dictionary alternatives,
+/// e.g. "רש״י" together with "רבי שלמה יצחקי".
+#[derive(Clone, Debug)]
+pub struct QueryExpansion {
+    /// The words as they appear in the query.
+    pub original: String,
+    /// The abbreviations or expansions searched in addition to them.
+    pub alternatives: Vec<String>,
+}
+
+/// Why a search engine call failed. In Dart it is thrown as a
+/// `SearchEngineError`, told apart by its `kind`; `withExceptions` on the
+/// futures and streams of the API rethrows it as the `SearchEngineException`
+/// subclass of its kind.
+#[derive(Clone, Debug)]
+pub struct SearchEngineError {
+    pub kind: SearchEngineErrorKind,
+    pub message: String,
+    /// For an invalid query, the character offset of the first error in the
+    /// query as rewritten for the query parser, see
+    /// [`QueryExplanation::parsed_query`], when it is known.
+    pub position: Option<u32>,
+}
+
+/// The kinds of [`SearchEngineError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchEngineErrorKind {
+    /// Another writer, usually another instance of the app, holds the index.
+    IndexLocked,
+    /// Index files are damaged or were written by an incompatible version.
+    CorruptIndex,
+    /// The query could not be parsed or asks for more than is supported.
+    InvalidQuery,
+    /// The index on disk was created with different fields; it has to be
+    /// deleted and rebuilt.
+    SchemaMismatch,
+    /// Reading or writing the index or a dictionary file failed.
+    Io,
+    /// Any other failure.
+    Other,
+}
+
+impl SearchEngineError {
+    fn new(kind: SearchEngineErrorKind, message: impl ToString) -> Self {
+        SearchEngineError {
+            kind,
+            message: message.to_string(),
+            position: None,
+        }
+    }
+
+    /// An invalid query error for `query`, located at the first error the
+    /// lenient parser finds in it.
+    fn invalid_query(query: &str, message: impl ToString) -> Self {
+        let (_, errors) = parse_query_lenient(query);
+        let position = errors
+            .first()
+            .map(|error| query[..error.pos.min(query.len())].chars().count() as u32);
+        SearchEngineError {
+            position,
+            ..Self::new(SearchEngineErrorKind::InvalidQuery, message)
+        }
+    }
+}
+
+impl std::fmt::Display for SearchEngineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.message)
+    }
+}
+
+impl std::error::Error for SearchEngineError {}
+
+impl From<TantivyError> for SearchEngineError {
+    fn from(error: TantivyError) -> Self {
+        let kind = match &error {
+            TantivyError::LockFailure(..) => SearchEngineErrorKind::IndexLocked,
+            TantivyError::DataCorruption(_)
+            | TantivyError::OpenReadError(_)
+            | TantivyError::IncompatibleIndex(_) => SearchEngineErrorKind::CorruptIndex,
+            TantivyError::SchemaError(_) | TantivyError::FieldNotFound(_) => {
+                SearchEngineErrorKind::SchemaMismatch
+            }
+            TantivyError::IoError(_)
+            | TantivyError::OpenDirectoryError(_)
+            | TantivyError::OpenWriteError(_) => SearchEngineErrorKind::Io,
+            _ => SearchEngineErrorKind::Other,
+        };
+        Self::new(kind, error)
+    }
+}
+
+impl From<std::io::Error> for SearchEngineError {
+    fn from(error: std::io::Error) -> Self {
+        Self::new(SearchEngineErrorKind::Io, error)
+    }
+}
+
+impl From<anyhow::Error> for SearchEngineError {
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<std::io::Error>() {
+            Ok(error) => error.into(),
+            Err(error) => Self::new(SearchEngineErrorKind::Other, format!("{error:#}")),
+        }
+    }
 }
 
 pub struct SearchEngine {
+    #[allow(dead_code)]
     path: String,
     schema: Schema,
     index: Index,
     index_writer: IndexWriter,
+    #[allow(dead_code)]
     index_reader: IndexReader,
 }
 
 impl SearchEngine {
-    pub fn new(path: &str) -> Self {
+    pub fn new(path: &str) -> Result<Self, SearchEngineError> {
         debug!("new path={}", path,);
-        let schema_builder = Schema::builder();
         let mut schema_builder = Schema::builder();
-        let text = schema_builder.add_text_field("text", TEXT | STORED | FAST);
-        let title = schema_builder.add_text_field(
+        schema_builder.add_text_field(
+            "text",
+            TextOptions::default()
+                .set_indexing_options(
+                    TextFieldIndexing::default()
+                        .set_tokenizer(analysis::HEBREW_TOKENIZER)
+                        .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+                )
+                .set_stored()
+                .set_fast(None),
+        );
+        schema_builder.add_text_field(
+            "skeleton",
+            TextOptions::default().set_indexing_options(
+                TextFieldIndexing::default()
+                    .set_tokenizer(analysis::SKELETON_TOKENIZER)
+                    .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+            ),
+        );
+        schema_builder.add_text_field(
+            "root",
+            TextOptions::default().set_indexing_options(
+                TextFieldIndexing::default()
+                    .set_tokenizer(analysis::ROOT_TOKENIZER)
+                    .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+            ),
+        );
+        schema_builder.add_text_field(
+            "gematria",
+            TextOptions::default().set_indexing_options(
+                TextFieldIndexing::default()
+                    .set_tokenizer(analysis::GEMATRIA_TOKENIZER)
+                    .set_index_option(IndexRecordOption::WithFreqs),
+            ),
+        );
+        schema_builder.add_text_field(
             "title",
             TextOptions::default()
                 .set_indexing_options(
@@ -54,28 +591,28 @@ impl SearchEngine {
                         .set_tokenizer("raw")
                         .set_fieldnorms(false),
                 )
-                .set_stored(),
+                .set_stored()
+                .set_fast(None),
         );
-        let id = schema_builder.add_u64_field("id", STORED | FAST);
-        let segment = schema_builder.add_u64_field("segment", STORED);
-        let isPdf = schema_builder.add_bool_field("isPdf", STORED);
-        let file_path = schema_builder.add_text_field("filePath", TEXT | STORED);
+        schema_builder.add_u64_field("id", STORED | FAST);
+        schema_builder.add_u64_field("segment", STORED | FAST);
+        schema_builder.add_bool_field("isPdf", STORED);
+        schema_builder.add_text_field("filePath", TEXT | STORED);
+        schema_builder.add_facet_field("category", FacetOptions::default());
         let schema = schema_builder.build();
-        let mmap_directory = MmapDirectory::open(path).expect("unable to open mmap directory");
-        let index = Index::open_or_create(mmap_directory, schema.clone());
-        let index = index.expect("Failed to create index").clone();
-        let index_reader = index.reader().expect("Failed to create index reader");
-        let index_writer = index
-            .writer(50_000_000)
-            .expect("Failed to create index writer");
-
-        SearchEngine {
+        let mmap_directory = MmapDirectory::open(path).map_err(TantivyError::from)?;
+        let index = Index::open_or_create(mmap_directory, schema.clone())?;
+        analysis::register_tokenizers(&index);
+        let index_reader = index.reader()?;
+        let index_writer = index.writer(50_000_000)?;
+
+        Ok(SearchEngine {
             path: path.to_string(),
-            index: index,
-            schema: schema,
-            index_writer: index_writer,
-            index_reader: index_reader,
-        }
+            index,
+            schema,
+            index_writer,
+            index_reader,
+        })
     }
 
     pub fn add_document(
@@ -86,269 +623,2677 @@ impl SearchEngine {
         _segment: u64,
         _is_pdf: bool,
         _file_path: &str,
-    ) -> Result<()> {
-        let title = self.schema.get_field("title").unwrap();
-        let text = self.schema.get_field("text").unwrap();
-        let id = self.schema.get_field("id").unwrap();
-        let segment = self.schema.get_field("segment").unwrap();
-        let is_pdf = self.schema.get_field("isPdf").unwrap();
-        let file_path = self.schema.get_field("filePath").unwrap();
+    ) -> Result<(), SearchEngineError> {
+        let title = self.schema.get_field("title")?;
+        let text = self.schema.get_field("text")?;
+        let skeleton = self.schema.get_field("skeleton")?;
+        let root = self.schema.get_field("root")?;
+        let gematria = self.schema.get_field("gematria")?;
+        let id = self.schema.get_field("id")?;
+        let segment = self.schema.get_field("segment")?;
+        let is_pdf = self.schema.get_field("isPdf")?;
+        let file_path = self.schema.get_field("filePath")?;
+        let category = self.schema.get_field("category")?;
+        // the category of a book is the folder it is in
+        let folder = _file_path
+            .rfind(['/', '\\'])
+            .map_or("", |end| &_file_path[..end]);
 
         self.index_writer.add_document(doc!(
         title => _title,
         text => _text,
+        skeleton => _text,
+        root => _text,
+        gematria => _text,
         id => _id,
         segment => _segment,
         is_pdf => _is_pdf,
-        file_path => _file_path
+        file_path => _file_path,
+        category => category_facet(folder)
         ))?;
 
         Ok(())
     }
-    pub fn commit(&mut self) -> Result<()> {
+    pub fn commit(&mut self) -> Result<(), SearchEngineError> {
         self.index_writer.commit()?;
         Ok(())
     }
     pub fn create_search_query(
         index: &Index,
         search_term: &str,
-        book_titles: &[String],
+        books: &BookFilter,
         fuzzy: bool,
-    ) -> Result<Box<dyn Query>> {
+        proximity: Option<Proximity>,
+        options: MatchOptions,
+    ) -> Result<Box<dyn Query>, SearchEngineError> {
         let schema = index.schema();
-        let text_field = schema.get_field("text").unwrap();
-        let title_field = schema.get_field("title").unwrap();
+        let text_field = schema.get_field("text")?;
 
         // Create the main text search query
-        let mut text_query: Box<dyn Query> = {
+        let text_query: Box<dyn Query> = {
+            // in case of proximity search, look for the words near each other
+            if let Some(proximity) = proximity {
+                if fuzzy {
+                    return Err(SearchEngineError::new(
+                        SearchEngineErrorKind::InvalidQuery,
+                        "a proximity search cannot be fuzzy",
+                    ));
+                }
+                Self::create_proximity_query(index, search_term, proximity, options)?
             // in case of fuzzy search, use a query parser with fuzzy query
-            if fuzzy {
-                let mut text_query = QueryParser::for_index(&index, vec![text_field]);
+            } else if fuzzy {
+                // a three-letter root one letter off is another root
+                if options.by_root {
+                    return Err(SearchEngineError::new(
+                        SearchEngineErrorKind::InvalidQuery,
+                        "a fuzzy search cannot match by root",
+                    ));
+                }
+                let search_term = &Self::rewrite_query(search_term).0;
+                let mut text_query = Self::query_parser(index, options);
                 text_query.set_conjunction_by_default();
-                text_query.set_field_fuzzy(text_field, false, 1, true);
-                let text_query = text_query.parse_query(search_term).unwrap();
-                Box::new(text_query) as Box<dyn Query>
+                let text_query = Self::parse_query(&text_query, search_term)?;
+                Self::fuzzy_terms(text_query, text_field, FUZZY_DISTANCE)
             // in case of exact search, use a term query
             } else {
-                Box::new(
-                    QueryParser::for_index(&index, vec![text_field])
-                        .parse_query(search_term)
-                        .unwrap(),
-                ) as Box<dyn Query>
+                let search_term = &Self::rewrite_query(search_term).0;
+                Self::parse_query(&Self::query_parser(index, options), search_term)?
             }
         };
 
-        // Create a TermSetQuery for exact matching of book titles
-        let title_terms: Vec<Term> = book_titles
+        Ok(Self::in_books(index, text_query, books))
+    }
+
+    /// `query` with its single words in `field` matched within `distance`
+    /// edits. The query parser's own fuzzy queries would also match the
+    /// marked forms a word is an edit away from, such as "*תורה" for
+    /// "תורה", and through them every word of which it is a stem.
+    fn fuzzy_terms(query: Box<dyn Query>, field: Field, distance: u8) -> Box<dyn Query> {
+        if let Some(boolean_query) = query.downcast_ref::<BooleanQuery>() {
+            let clauses = boolean_query.clauses().iter().map(|(occur, clause)| {
+                let clause = Self::fuzzy_terms(clause.box_clone(), field, distance);
+                (*occur, clause)
+            });
+            return Box::new(BooleanQuery::new(clauses.collect()));
+        }
+        match query.downcast_ref::<TermQuery>() {
+            Some(term_query) if term_query.term().field() == field => {
+                Box::new(fuzzy::FuzzyQuery::new(term_query.term().clone(), distance))
+            }
+            _ => query,
+        }
+    }
+
+    /// Restricts `query` to the documents of `books`. Searching all books
+    /// leaves it unrestricted.
+    fn in_books(index: &Index, query: Box<dyn Query>, books: &BookFilter) -> Box<dyn Query> {
+        let mut clauses = vec![(Occur::Must, query)];
+        if !books.all_books {
+            let included =
+                Self::books_query(index, &books.include_titles, &books.include_categories);
+            clauses.push((Occur::Must, included));
+        }
+        if !books.exclude_titles.is_empty() || !books.exclude_categories.is_empty() {
+            let excluded =
+                Self::books_query(index, &books.exclude_titles, &books.exclude_categories);
+            clauses.push((Occur::MustNot, excluded));
+        }
+        if clauses.len() == 1 {
+            return clauses.pop().unwrap().1;
+        }
+        Box::new(BooleanQuery::new(clauses))
+    }
+
+    /// Query for the documents of the books titled `titles` and of the books
+    /// in `categories`.
+    fn books_query(index: &Index, titles: &[String], categories: &[String]) -> Box<dyn Query> {
+        let title_field = index.schema().get_field("title").unwrap();
+        let category_field = index.schema().get_field("category").unwrap();
+
+        // Create a TermSetQuery for exact matching of book titles, and of
+        // categories, which are indexed along with those above them
+        let title_terms = titles
             .iter()
-            .map(|title| Term::from_field_text(title_field, title))
-            .collect();
-        let title_filter = TermSetQuery::new(title_terms);
+            .map(|title| Term::from_field_text(title_field, title));
+        let category_terms = categories
+            .iter()
+            .map(|category| Term::from_facet(category_field, &category_facet(category)));
+        Box::new(TermSetQuery::new(title_terms.chain(category_terms)))
+    }
 
-        // Combine the text search and title filter
-        let bool_query = BooleanQuery::new(vec![
-            (Occur::Must, Box::new(text_query) as Box<dyn Query>),
-            (Occur::Must, Box::new(title_filter) as Box<dyn Query>),
-        ]);
-        Ok(Box::new(bool_query))
+    /// Query for the words of `search_term` within `proximity` of each other.
+    /// The words are taken as typed, without query syntax or abbreviation
+    /// expansion.
+    fn create_proximity_query(
+        index: &Index,
+        search_term: &str,
+        proximity: Proximity,
+        options: MatchOptions,
+    ) -> Result<Box<dyn Query>, SearchEngineError> {
+        Self::in_match_fields(index, search_term, options, |terms| {
+            Self::proximity_phrases(terms, proximity)
+        })
     }
 
-    pub fn search(
-        &mut self,
-        query: &str,
-        books: &Vec<String>,
-        limit: u32,
-        fuzzy: bool,
-    ) -> Result<Vec<SearchResult>> {
-        let index = &self.index;
-        let schema = &self.schema;
-        let query = Self::create_search_query(index, query, books, fuzzy)?;
-        let searcher = index.reader()?.searcher();
+    /// Compiles `query` into a query restricted to `books`. Its words are
+    /// analyzed according to the match options like those of a query string,
+    /// but never parsed, so any input is valid.
+    pub fn create_structured_query(
+        index: &Index,
+        query: &SearchQuery,
+        books: &BookFilter,
+        options: MatchOptions,
+    ) -> Result<Box<dyn Query>, SearchEngineError> {
+        let mut clauses = Vec::new();
+        for clause in &query.clauses {
+            let words = clause.words.join(" ");
+            let clause_query = Self::in_match_fields(index, &words, options, |terms| {
+                Self::clause_query(terms, clause)
+            })?;
+            let occur = match clause.occur {
+                ClauseOccur::Should => Occur::Should,
+                ClauseOccur::Must => Occur::Must,
+                ClauseOccur::MustNot => Occur::MustNot,
+            };
+            clauses.push((occur, clause_query));
+        }
+        // excluding words alone excludes them from everything
+        if clauses.iter().all(|(occur, _)| *occur == Occur::MustNot) {
+            clauses.push((Occur::Must, Box::new(AllQuery)));
+        }
+        // filters are kept apart from the words, as required clauses next to
+        // them would leave optional words only ranking the results
+        let mut filtered: Vec<(Occur, Box<dyn Query>)> =
+            vec![(Occur::Must, Box::new(BooleanQuery::new(clauses)))];
+        for filter in &query.filters {
+            filtered.push(Self::filter_clause(index, filter));
+        }
+        let filtered_query = if filtered.len() == 1 {
+            filtered.pop().unwrap().1
+        } else {
+            Box::new(BooleanQuery::new(filtered))
+        };
+        Ok(Self::in_books(index, filtered_query, books))
+    }
 
-        let mut results = Vec::<SearchResult>::new();
-        let title_field = schema.get_field("title")?;
-        let text_field = schema.get_field("text")?;
-        let id_field = schema.get_field("id")?;
-        let segment_field = schema.get_field("segment")?;
-        let is_pdf_field = schema.get_field("isPdf")?;
-        let file_path_field = schema.get_field("filePath")?;
-        let mut snippet_generator = SnippetGenerator::create(&searcher, &*query, text_field)?;
-        snippet_generator.set_max_num_chars(800);
-
-        let top_docs: Vec<DocAddress> = {
-            if fuzzy {
-                // sort by relevance
-                let collector_by_relanace = TopDocs::with_limit(limit as usize);
-                let top_docs_by_relevance = searcher.search(&query, &collector_by_relanace)?;
-                top_docs_by_relevance
-                    .into_iter()
-                    .map(|(score, doc_address)| (doc_address))
-                    .collect()
-            } else {
-                // sort by id (ascending)
-                let collector_by_id = TopDocs::with_limit(limit as usize)
-                    .order_by_fast_field::<u64>("id", Order::Asc);
-                let top_docs_by_id = searcher.search(&query, &collector_by_id).unwrap();
-                top_docs_by_id
-                    .into_iter()
-                    .map(|(id, doc_address)| (doc_address))
+    fn clause_query(
+        terms: Vec<Term>,
+        clause: &QueryClause,
+    ) -> Result<Box<dyn Query>, SearchEngineError> {
+        match (&terms[..], clause.proximity) {
+            ([term], _) if clause.fuzziness > 0 => {
+                let distance = clause.fuzziness.min(MAX_FUZZINESS);
+                Ok(Box::new(fuzzy::FuzzyQuery::new(term.clone(), distance)))
+            }
+            (_, Some(proximity)) => Self::proximity_phrases(terms, proximity),
+            // an exact phrase is a single query however long it is
+            (_, None) if terms.len() > 1 => Ok(Box::new(PhraseQuery::new(terms))),
+            (_, None) => Self::proximity_phrases(terms, EXACT_PHRASE),
+        }
+    }
+
+    fn filter_clause(index: &Index, filter: &FieldFilter) -> (Occur, Box<dyn Query>) {
+        let schema = index.schema();
+        let terms: Vec<Term> = match filter.field {
+            FilterField::Title => {
+                let title_field = schema.get_field("title").unwrap();
+                filter
+                    .values
+                    .iter()
+                    .map(|title| Term::from_field_text(title_field, title))
                     .collect()
             }
+            FilterField::FilePath => {
+                let file_path_field = schema.get_field("filePath").unwrap();
+                let mut tokenizer = index.tokenizer_for_field(file_path_field).unwrap();
+                let mut terms = Vec::new();
+                for value in &filter.values {
+                    tokenizer.token_stream(value).process(&mut |token| {
+                        terms.push(Term::from_field_text(file_path_field, &token.text))
+                    });
+                }
+                terms
+            }
         };
+        let occur = if filter.exclude {
+            Occur::MustNot
+        } else {
+            Occur::Must
+        };
+        (occur, Box::new(TermSetQuery::new(terms)))
+    }
 
-        for doc_address in top_docs {
-            match searcher.doc::<TantivyDocument>(doc_address) {
-                Ok(retrieved_doc) => {
-                    let title = retrieved_doc
-                        .get_first(title_field)
-                        .and_then(|v| match v {
-                            OwnedValue::Str(s) => Some(s.clone()),
-                            _ => None,
-                        })
-                        .unwrap_or_default();
-                    let text = retrieved_doc
-                        .get_first(text_field)
-                        .and_then(|v| match v {
-                            OwnedValue::Str(s) => Some(s.clone()),
-                            _ => None,
-                        })
-                        .unwrap_or_default();
-                    let mut snippet = snippet_generator.snippet(&text);
-                    snippet.set_snippet_prefix_postfix("<font color=red>", "</font>");
-                    let id = retrieved_doc
-                        .get_first(id_field)
-                        .and_then(|v| match v {
-                            OwnedValue::U64(y) => Some(*y),
-                            _ => None,
-                        })
-                        .unwrap_or_default();
-                    let segment = retrieved_doc
-                        .get_first(segment_field)
-                        .and_then(|v| match v {
-                            OwnedValue::U64(y) => Some(*y),
-                            _ => None,
-                        })
-                        .unwrap_or_default();
-                    let is_pdf = retrieved_doc
-                        .get_first(is_pdf_field)
-                        .and_then(|v| match v {
-                            OwnedValue::Bool(y) => Some(*y),
-                            _ => None,
-                        })
-                        .unwrap_or_default();
-                    let file_path = retrieved_doc
-                        .get_first(file_path_field)
-                        .and_then(|v| match v {
-                            OwnedValue::Str(s) => Some(s.clone()),
-                            _ => None,
-                        })
-                        .unwrap_or_default();
-                    let text = {
-                        if fuzzy {
-                            text
-                        } else {
-                            snippet.to_html()
-                        }
-                    };
+    /// Query for `text` in every field the match options look words up in,
+    /// built by `words_query` from the terms `text` analyzes to in each of
+    /// them. Matches on the skeleton weigh less than the others.
+    fn in_match_fields(
+        index: &Index,
+        text: &str,
+        options: MatchOptions,
+        words_query: impl Fn(Vec<Term>) -> Result<Box<dyn Query>, SearchEngineError>,
+    ) -> Result<Box<dyn Query>, SearchEngineError> {
+        let schema = index.schema();
+        let skeleton_field = schema.get_field("skeleton")?;
+        let tokenizers = analysis::query_tokenizers(options);
+        let text = analysis::normalize_query(text);
+        let mut alternatives = Vec::new();
+        for field in Self::match_fields(&schema, options) {
+            let terms = query_terms(&schema, &tokenizers, field, &text);
+            let mut query = words_query(terms)?;
+            if field == skeleton_field {
+                query = Box::new(BoostQuery::new(query, SKELETON_BOOST));
+            }
+            alternatives.push((Occur::Should, query));
+        }
+        Ok(Box::new(BooleanQuery::new(alternatives)))
+    }
 
-                    let result = SearchResult {
-                        title,
-                        text,
-                        id,
-                        segment,
-                        is_pdf,
-                        file_path,
-                    };
-                    results.push(result);
+    /// Slop phrase queries for `terms` within `proximity` of each other. A
+    /// slop phrase allows each term within `slop` positions of its offset
+    /// from the term before it, either way, so an unordered search has every
+    /// offset at 0. An ordered search allows gaps of 1 to `max_distance` + 1
+    /// positions: for two words, one window around `max_distance` / 2 + 1
+    /// for an even distance, and for an odd one two windows one position
+    /// narrower. A phrase of more words spends its slop on all of them
+    /// together, so every gap is listed instead, without slop. Every term
+    /// picks one of the gaps.
+    fn proximity_phrases(
+        terms: Vec<Term>,
+        proximity: Proximity,
+    ) -> Result<Box<dyn Query>, SearchEngineError> {
+        if terms.len() > MAX_PROXIMITY_WORDS {
+            return Err(SearchEngineError::new(
+                SearchEngineErrorKind::InvalidQuery,
+                format!("a proximity search takes at most {MAX_PROXIMITY_WORDS} words"),
+            ));
+        }
+        let distance = proximity.max_distance;
+        Ok(match terms.len() {
+            0 => Box::new(EmptyQuery),
+            1 => Box::new(TermQuery::new(
+                terms[0].clone(),
+                IndexRecordOption::WithFreqsAndPositions,
+            )),
+            _ if !proximity.ordered => {
+                let terms = terms.into_iter().map(|term| (0, term)).collect();
+                Box::new(PhraseQuery::new_with_offset_and_slop(
+                    terms,
+                    distance.saturating_add(1),
+                ))
+            }
+            2 => {
+                let slop = distance / 2;
+                let gaps = if distance.is_multiple_of(2) {
+                    vec![slop as usize + 1]
+                } else {
+                    vec![slop as usize + 1, slop as usize + 2]
+                };
+                Self::ordered_phrases(terms, &gaps, slop)
+            }
+            _ => {
+                let phrases = (distance as u64 + 1).checked_pow(terms.len() as u32 - 1);
+                if phrases.is_none_or(|phrases| phrases > MAX_PROXIMITY_PHRASES) {
+                    return Err(SearchEngineError::new(
+                        SearchEngineErrorKind::InvalidQuery,
+                        format!(
+                            "a proximity search of {} words this far apart is too broad",
+                            terms.len()
+                        ),
+                    ));
                 }
-                Err(_) => continue,
+                let gaps: Vec<usize> = (1..=distance as usize + 1).collect();
+                Self::ordered_phrases(terms, &gaps, 0)
             }
+        })
+    }
+
+    /// Slop phrase queries for `terms` in order, each at one of `gaps`
+    /// positions after the term before it, give or take `slop`.
+    fn ordered_phrases(terms: Vec<Term>, gaps: &[usize], slop: u32) -> Box<dyn Query> {
+        let mut offsets = vec![vec![0]];
+        for _ in 1..terms.len() {
+            offsets = offsets
+                .iter()
+                .flat_map(|offsets| {
+                    gaps.iter().map(|gap| {
+                        let mut offsets = offsets.clone();
+                        offsets.push(offsets.last().unwrap() + gap);
+                        offsets
+                    })
+                })
+                .collect();
         }
-        Ok(results)
+        let phrases = offsets.into_iter().map(|offsets| {
+            let terms = offsets.into_iter().zip(terms.iter().cloned()).collect();
+            let phrase = PhraseQuery::new_with_offset_and_slop(terms, slop);
+            (Occur::Should, Box::new(phrase) as Box<dyn Query>)
+        });
+        Box::new(BooleanQuery::new(phrases.collect()))
     }
-    pub fn search_stream(
+
+    /// Query for runs of consecutive words whose gematria is `value`.
+    fn create_gematria_query(
+        index: &Index,
+        value: u32,
+        books: &BookFilter,
+        options: GematriaOptions,
+    ) -> Box<dyn Query> {
+        let gematria_field = index.schema().get_field("gematria").unwrap();
+        let max_words = (options.max_words as usize).min(analysis::gematria::MAX_PHRASE_WORDS);
+        let min_words = (options.min_words as usize).max(1);
+        let runs = (min_words..=max_words).map(|words| {
+            let term = analysis::gematria::term(options.method, words, value);
+            let term = Term::from_field_text(gematria_field, &term);
+            let query = TermQuery::new(term, IndexRecordOption::WithFreqs);
+            (Occur::Should, Box::new(query) as Box<dyn Query>)
+        });
+        let gematria_query = Box::new(BooleanQuery::new(runs.collect()));
+        Self::in_books(index, gematria_query, books)
+    }
+
+    /// Parses `query` with `query_parser`, reporting where it is invalid.
+    fn parse_query(
+        query_parser: &QueryParser,
+        query: &str,
+    ) -> Result<Box<dyn Query>, SearchEngineError> {
+        query_parser
+            .parse_query(query)
+            .map_err(|error| SearchEngineError::invalid_query(query, error))
+    }
+
+    /// Rewrites `search_term` for the query parser: quotes inside
+    /// abbreviations are normalized, Hebrew numerals bounding ranges are
+    /// written in digits and dictionary abbreviations and their expansions
+    /// are searched for each other.
+    ///
+    /// Numbers match Hebrew numerals of the same value either way, so
+    /// "פרק 21" finds "פרק כ״א" and "פרק כ״א" finds "פרק 21", and ranges
+    /// such as "פרק [10 TO 20]" or "דף [י TO כ]" find the numbers between
+    /// their bounds.
+    fn rewrite_query(search_term: &str) -> (String, Vec<QueryExpansion>) {
+        let normalized = analysis::normalize_query(search_term);
+        analysis::abbreviations::expand(&analysis::numerals::rewrite_ranges(&normalized, "text"))
+    }
+
+    /// Describes the query `create_search_query` builds from `search_term`
+    /// with the same arguments, for showing the user how it was read: which
+    /// words are required or excluded, which are looked up as phrases, which
+    /// indexed words fuzzy words match and, for an invalid query, why.
+    pub fn explain_query(
+        &self,
+        search_term: &str,
+        fuzzy: bool,
+        proximity: Option<Proximity>,
+        options: MatchOptions,
+    ) -> Result<QueryExplanation, SearchEngineError> {
+        let (parsed_query, expansions) = Self::rewrite_query(search_term);
+        let mut explanation = QueryExplanation {
+            parsed_query,
+            expansions,
+            root: None,
+            error: None,
+        };
+        if let Err(error) = Self::create_search_query(
+            &self.index,
+            search_term,
+            &BookFilter::default(),
+            fuzzy,
+            proximity,
+            options,
+        ) {
+            explanation.error = Some(error);
+            return Ok(explanation);
+        }
+        let explainer = QueryExplainer {
+            schema: self.index.schema(),
+            tokenizers: analysis::query_tokenizers(options),
+            searcher: self.index.reader()?.searcher(),
+            fields: Self::match_fields(&self.index.schema(), options),
+            fuzzy,
+        };
+        explanation.root = Some(match proximity {
+            Some(proximity) => explainer.proximity_node(search_term, proximity)?,
+            None => {
+                let parsed_query = &explanation.parsed_query;
+                let ast = query_grammar::parse_query(parsed_query)
+                    .map_err(|_| SearchEngineError::invalid_query(parsed_query, "Syntax Error"))?;
+                explainer.ast_node(&ast, Occur::Must)?
+            }
+        });
+        Ok(explanation)
+    }
+
+    /// Explains the score of the result with id `id` of the search
+    /// `create_search_query` builds from the other arguments, for finding
+    /// out why it ranks where it does. `None` when it is not a result.
+    #[allow(clippy::too_many_arguments)]
+    pub fn explain_score(
+        &self,
+        query: &str,
+        books: &BookFilter,
+        fuzzy: bool,
+        proximity: Option<Proximity>,
+        options: MatchOptions,
+        id: u64,
+    ) -> Result<Option<ScoreExplanation>, SearchEngineError> {
+        let query =
+            Self::create_search_query(&self.index, query, books, fuzzy, proximity, options)?;
+        let searcher = self.index.reader()?.searcher();
+        let by_id =
+            RangeQuery::new_u64_bounds("id".to_string(), Bound::Included(id), Bound::Included(id));
+        let result = BooleanQuery::intersection(vec![query.box_clone(), Box::new(by_id)]);
+        let Some((_, doc_address)) = searcher.search(&result, &TopDocs::with_limit(1))?.pop()
+        else {
+            return Ok(None);
+        };
+        let explanation = query.explain(&searcher, doc_address)?;
+        let explanation = serde_json::to_value(&explanation)
+            .and_then(serde_json::from_value)
+            .map_err(|error| SearchEngineError::new(SearchEngineErrorKind::Other, error))?;
+        Ok(Some(explanation))
+    }
+
+    /// Query parser analyzing query words according to the match options.
+    /// With spelling-insensitive matching each word is also looked up by its
+    /// skeleton, at a lower weight than its exact spelling; root matching
+    /// looks words up among the candidate roots of the indexed words instead.
+    fn query_parser(index: &Index, options: MatchOptions) -> QueryParser {
+        let schema = index.schema();
+        let skeleton_field = schema.get_field("skeleton").unwrap();
+        let fields = Self::match_fields(&schema, options);
+        let mut query_parser =
+            QueryParser::new(schema, fields, analysis::query_tokenizers(options));
+        query_parser.set_field_boost(skeleton_field, SKELETON_BOOST);
+        query_parser
+    }
+
+    /// The fields query words are looked up in: the candidate roots for root
+    /// matching, otherwise the spelling, and the skeleton too for
+    /// spelling-insensitive matching.
+    fn match_fields(schema: &Schema, options: MatchOptions) -> Vec<Field> {
+        let text_field = schema.get_field("text").unwrap();
+        if options.by_root {
+            vec![schema.get_field("root").unwrap()]
+        } else if options.spelling_insensitive {
+            vec![text_field, schema.get_field("skeleton").unwrap()]
+        } else {
+            vec![text_field]
+        }
+    }
+
+    /// Searches `books` for `query` and returns `limit` results in `order`,
+    /// after skipping `offset` results, together with the number of results
+    /// overall, per book and per category. With `after`, the cursor of the
+    /// last result of a page, the results following it are returned, so the
+    /// next page costs no more than the first. Each result comes with the
+    /// `context_segments` segments before and after it in its book.
+    #[allow(clippy::too_many_arguments)]
+    pub fn search(
         &mut self,
         query: &str,
-        sink: StreamSink<Vec<SearchResult>>,
-        books: &Vec<String>,
+        books: &BookFilter,
         limit: u32,
         fuzzy: bool,
-    ) -> Result<()> {
-        let index = &self.index;
-        let schema = &self.schema;
-        let query = Self::create_search_query(index, query, books, fuzzy).unwrap();
-        let searcher = index.reader().unwrap().searcher();
-        let top_docs = searcher
-            .search(&query, &TopDocs::with_limit(limit as usize))
-            .unwrap();
-        let mut results = Vec::<SearchResult>::new();
-        let title_field = schema.get_field("title").unwrap();
-        let text_field = schema.get_field("text").unwrap();
-        let id_field = schema.get_field("id").unwrap();
-        let segment_field = schema.get_field("segment").unwrap();
-        let is_pdf_field = schema.get_field("isPdf").unwrap();
-        let file_path_field = schema.get_field("filePath").unwrap();
+        proximity: Option<Proximity>,
+        options: MatchOptions,
+        snippet_options: SnippetOptions,
+        context_segments: u32,
+        order: SortOrder,
+        offset: u32,
+        after: Option<SearchCursor>,
+    ) -> Result<SearchResponse, SearchEngineError> {
+        let snippets = self.snippets(query, fuzzy, proximity, options, snippet_options)?;
+        let query =
+            Self::create_search_query(&self.index, query, books, fuzzy, proximity, options)?;
+        let page = Page {
+            order,
+            offset,
+            limit,
+            after,
+        };
+        let mut response = self.collect_results(&*query, page, snippets)?;
+        self.add_neighbors(&mut response.hits, context_segments)?;
+        Ok(response)
+    }
 
-        for (_score, doc_address) in top_docs {
-            match searcher.doc::<TantivyDocument>(doc_address) {
-                Ok(retrieved_doc) => {
-                    let title = retrieved_doc
-                        .get_first(title_field)
-                        .and_then(|v| match v {
-                            OwnedValue::Str(s) => Some(s.clone()),
-                            _ => None,
-                        })
-                        .unwrap_or_default();
-                    let text = retrieved_doc
-                        .get_first(text_field)
-                        .and_then(|v| match v {
-                            OwnedValue::Str(s) => Some(s.clone()),
-                            _ => None,
-                        })
-                        .unwrap_or_default();
-                    let id = retrieved_doc
-                        .get_first(id_field)
-                        .and_then(|v| match v {
-                            OwnedValue::U64(y) => Some(*y),
-                            _ => None,
-                        })
-                        .unwrap_or_default();
-                    let segment = retrieved_doc
-                        .get_first(segment_field)
-                        .and_then(|v| match v {
-                            OwnedValue::U64(y) => Some(*y),
-                            _ => None,
-                        })
-                        .unwrap_or_default();
-                    let is_pdf = retrieved_doc
-                        .get_first(is_pdf_field)
-                        .and_then(|v| match v {
-                            OwnedValue::Bool(y) => Some(*y),
-                            _ => None,
-                        })
-                        .unwrap_or_default();
-                    let file_path = retrieved_doc
-                        .get_first(file_path_field)
-                        .and_then(|v| match v {
-                            OwnedValue::Str(s) => Some(s.clone()),
-                            _ => None,
-                        })
-                        .unwrap_or_default();
-
-                    let result = SearchResult {
-                        title,
-                        text,
-                        id,
-                        segment,
-                        is_pdf,
-                        file_path,
-                    };
-                    results.push(result);
-                    sink.add(results.clone());
-                }
-                Err(_) => continue,
-            }
+    /// Fills in the `context` segments before and after each of `results`,
+    /// all read in a single search.
+    fn add_neighbors(
+        &self,
+        results: &mut [SearchResult],
+        context: u32,
+    ) -> Result<(), SearchEngineError> {
+        if context == 0 || results.is_empty() {
+            return Ok(());
+        }
+        let context = context as u64;
+        let searcher = self.index.reader()?.searcher();
+        let title_field = self.schema.get_field("title")?;
+        let text_field = self.schema.get_field("text")?;
+        let segment_field = self.schema.get_field("segment")?;
+
+        let neighborhoods = results
+            .iter()
+            .map(|result| {
+                let title = Term::from_field_text(title_field, &result.title);
+                let segments = RangeQuery::new_u64_bounds(
+                    "segment".to_string(),
+                    Bound::Included(result.segment.saturating_sub(context)),
+                    Bound::Included(result.segment.saturating_add(context)),
+                );
+                let neighborhood = BooleanQuery::intersection(vec![
+                    Box::new(TermQuery::new(title, IndexRecordOption::Basic)),
+                    Box::new(segments),
+                ]);
+                (Occur::Should, Box::new(neighborhood) as Box<dyn Query>)
+            })
+            .collect();
+        let mut texts = HashMap::new();
+        for doc_address in searcher.search(&BooleanQuery::new(neighborhoods), &DocSetCollector)? {
+            let retrieved_doc = searcher.doc::<TantivyDocument>(doc_address)?;
+            let title = retrieved_doc
+                .get_first(title_field)
+                .and_then(|v| match v {
+                    OwnedValue::Str(s) => Some(s.clone()),
+                    _ => None,
+                })
+                .unwrap_or_default();
+            let segment = retrieved_doc
+                .get_first(segment_field)
+                .and_then(|v| match v {
+                    OwnedValue::U64(y) => Some(*y),
+                    _ => None,
+                })
+                .unwrap_or_default();
+            let text = retrieved_doc
+                .get_first(text_field)
+                .and_then(|v| match v {
+                    OwnedValue::Str(s) => Some(s.clone()),
+                    _ => None,
+                })
+                .unwrap_or_default();
+            texts.insert((title, segment), text);
+        }
+
+        for result in results {
+            let neighbor = |segment: u64| {
+                let text = texts.get(&(result.title.clone(), segment))?;
+                Some(NeighborSegment {
+                    segment,
+                    text: text.clone(),
+                })
+            };
+            result.preceding = (result.segment.saturating_sub(context)..result.segment)
+                .filter_map(neighbor)
+                .collect();
+            // the result itself is skipped rather than added to, which
+            // could overflow
+            result.following = (result.segment..=result.segment.saturating_add(context))
+                .skip(1)
+                .filter_map(neighbor)
+                .collect();
         }
         Ok(())
     }
+
+    /// Finds every match of `query`, searched for like `search` does with
+    /// the same arguments, in the segments of the book titled `title` from
+    /// `segment_start` up to `segment_end`, excluded, for highlighting them
+    /// all when the book is opened. Segments the query does not match are
+    /// left out, and in the others matches are found the way snippets
+    /// highlight them, fuzzy matches and other forms of the words included.
+    #[allow(clippy::too_many_arguments)]
+    pub fn highlight_in_document(
+        &self,
+        query: &str,
+        title: &str,
+        segment_start: u64,
+        segment_end: u64,
+        fuzzy: bool,
+        proximity: Option<Proximity>,
+        options: MatchOptions,
+    ) -> Result<Vec<SegmentHighlights>, SearchEngineError> {
+        let snippets =
+            self.snippets(query, fuzzy, proximity, options, SnippetOptions::default())?;
+        let books = BookFilter {
+            include_titles: vec![title.to_string()],
+            ..BookFilter::default()
+        };
+        let query =
+            Self::create_search_query(&self.index, query, &books, fuzzy, proximity, options)?;
+        let searcher = self.index.reader()?.searcher();
+        let terms = Self::snippet_terms(&searcher, &*query, &snippets)?;
+        let mut analyzer = self.index.tokenizer_for_field(snippets.field)?;
+        let text_field = self.schema.get_field("text")?;
+        let segment_field = self.schema.get_field("segment")?;
+
+        let segments = BooleanQuery::intersection(vec![
+            query.box_clone(),
+            Box::new(RangeQuery::new_u64(
+                "segment".to_string(),
+                segment_start..segment_end,
+            )),
+        ]);
+        let mut highlights = Vec::new();
+        for doc_address in searcher.search(&segments, &DocSetCollector)? {
+            let retrieved_doc = searcher.doc::<TantivyDocument>(doc_address)?;
+            let text = retrieved_doc
+                .get_first(text_field)
+                .and_then(|v| match v {
+                    OwnedValue::Str(s) => Some(s.as_str()),
+                    _ => None,
+                })
+                .unwrap_or_default();
+            let segment = retrieved_doc
+                .get_first(segment_field)
+                .and_then(|v| match v {
+                    OwnedValue::U64(y) => Some(*y),
+                    _ => None,
+                })
+                .unwrap_or_default();
+            let fragment = snippet::whole(text, &mut analyzer, &|term| terms.score(term));
+            if !fragment.matches.is_empty() {
+                highlights.push(SegmentHighlights {
+                    segment,
+                    highlights: Self::snippet(text, vec![fragment], &snippets).1,
+                });
+            }
+        }
+        highlights.sort_by_key(|segment| segment.segment);
+        Ok(highlights)
+    }
+
+    /// How to make the snippets of the results of a search for
+    /// `search_term` with the other arguments of `search`.
+    fn snippets(
+        &self,
+        search_term: &str,
+        fuzzy: bool,
+        proximity: Option<Proximity>,
+        options: MatchOptions,
+        snippet_options: SnippetOptions,
+    ) -> Result<Snippets, SearchEngineError> {
+        // proximity searches are never fuzzy
+        let fuzzy_words = match proximity {
+            None if fuzzy => Self::fuzzy_words(search_term),
+            _ => Vec::new(),
+        };
+        let field = self.snippet_field(options);
+        Ok(Snippets {
+            field,
+            proximity,
+            fuzzy_terms: self.fuzzy_terms_in(field, &fuzzy_words, options),
+            options: snippet_options,
+        })
+    }
+
+    /// The fuzzy words of `search_term`, each with the typos it tolerates.
+    /// None for a query that does not parse, which the search reports.
+    fn fuzzy_words(search_term: &str) -> Vec<(String, u8)> {
+        let mut words = Vec::new();
+        if let Ok(ast) = query_grammar::parse_query(&Self::rewrite_query(search_term).0) {
+            fuzzy_literals(&ast, &mut words);
+        }
+        words
+            .into_iter()
+            .map(|word| (word, FUZZY_DISTANCE))
+            .collect()
+    }
+
+    /// The terms of `field` for fuzzy `words`, each with the typos it
+    /// tolerates. Words analyzed to several terms are not fuzzy.
+    fn fuzzy_terms_in(
+        &self,
+        field: Field,
+        words: &[(String, u8)],
+        options: MatchOptions,
+    ) -> Vec<(Term, u8)> {
+        let tokenizers = analysis::query_tokenizers(options);
+        let mut fuzzy_terms = Vec::new();
+        for (word, distance) in words {
+            let word = analysis::normalize_query(word);
+            if let [term] = &query_terms(&self.schema, &tokenizers, field, &word)[..] {
+                fuzzy_terms.push((term.clone(), *distance));
+            }
+        }
+        fuzzy_terms
+    }
+
+    /// Searches with a query assembled from parts, see [`SearchQuery`].
+    /// Results come in `order`, with their matches highlighted.
+    #[allow(clippy::too_many_arguments)]
+    pub fn search_structured(
+        &mut self,
+        query: &SearchQuery,
+        books: &BookFilter,
+        limit: u32,
+        options: MatchOptions,
+        snippet_options: SnippetOptions,
+        order: SortOrder,
+    ) -> Result<SearchResponse, SearchEngineError> {
+        let proximity = query
+            .clauses
+            .iter()
+            .filter_map(|clause| clause.proximity)
+            .max_by_key(|proximity| proximity.max_distance);
+        let fuzzy_words: Vec<(String, u8)> = query
+            .clauses
+            .iter()
+            .filter(|clause| clause.fuzziness > 0 && !matches!(clause.occur, ClauseOccur::MustNot))
+            .map(|clause| (clause.words.join(" "), clause.fuzziness.min(MAX_FUZZINESS)))
+            .collect();
+        let query = Self::create_structured_query(&self.index, query, books, options)?;
+        let field = self.snippet_field(options);
+        let snippets = Snippets {
+            field,
+            proximity,
+            fuzzy_terms: self.fuzzy_terms_in(field, &fuzzy_words, options),
+            options: snippet_options,
+        };
+        self.collect_results(&*query, Page::first(limit, order), snippets)
+    }
+
+    /// The field whose analyzer finds the words matched with `options` in
+    /// stored text, for highlighting them.
+    fn snippet_field(&self, options: MatchOptions) -> Field {
+        // the skeleton analyzer also finds the words matched by their exact spelling
+        let name = if options.by_root {
+            "root"
+        } else if options.spelling_insensitive {
+            "skeleton"
+        } else {
+            "text"
+        };
+        self.schema.get_field(name).unwrap()
+    }
+
+    /// Finds words, and runs of consecutive words, whose gematria is
+    /// `value`, with the matching words highlighted. Results come in
+    /// document order.
+    pub fn search_gematria(
+        &mut self,
+        value: u32,
+        books: &BookFilter,
+        limit: u32,
+        options: GematriaOptions,
+        snippet_options: SnippetOptions,
+    ) -> Result<SearchResponse, SearchEngineError> {
+        let query = Self::create_gematria_query(&self.index, value, books, options);
+        let snippets = Snippets {
+            field: self.schema.get_field("gematria")?,
+            proximity: None,
+            fuzzy_terms: Vec::new(),
+            options: snippet_options,
+        };
+        let page = Page::first(limit, SortOrder::LibraryOrder);
+        self.collect_results(&*query, page, snippets)
+    }
+
+    /// The hits of `query` on `page`, with their cursors, and the number of
+    /// hits overall, per book and per category, counted in the same pass.
+    fn top_docs(
+        searcher: &Searcher,
+        query: &dyn Query,
+        page: &Page,
+    ) -> Result<Hits, SearchEngineError> {
+        let segment_ords: HashMap<SegmentId, u32> = searcher
+            .segment_readers()
+            .iter()
+            .enumerate()
+            .map(|(segment_ord, reader)| (reader.segment_id(), segment_ord as u32))
+            .collect();
+        let order = page.order;
+        let after = page.after.clone();
+        let by_title = matches!(order, SortOrder::Title | SortOrder::TitleDescending);
+        let limit = (page.limit > 0).then_some(page.limit as usize);
+        let collector = limit.map(|limit| {
+            TopDocs::with_limit(limit)
+                .and_offset(page.offset as usize)
+                .tweak_score(move |segment_reader: &SegmentReader| {
+                    let segment_ord = segment_ords[&segment_reader.segment_id()];
+                    let fast_fields = segment_reader.fast_fields();
+                    let ids = fast_fields.u64("id").ok();
+                    let titles = fast_fields.str("title").ok().flatten().filter(|_| by_title);
+                    let segments = fast_fields.u64("segment").ok();
+                    let after = after.clone();
+                    move |doc: DocId, score: Score| {
+                        let mut cursor = SearchCursor {
+                            score,
+                            id: ids
+                                .as_ref()
+                                .and_then(|ids| ids.first(doc))
+                                .unwrap_or_default(),
+                            title: String::new(),
+                            segment: segments
+                                .as_ref()
+                                .and_then(|segments| segments.first(doc))
+                                .unwrap_or_default(),
+                            segment_ord,
+                            doc_id: doc,
+                        };
+                        if let Some(titles) = &titles {
+                            if let Some(ord) = titles.term_ords(doc).next() {
+                                let _ = titles.ord_to_str(ord, &mut cursor.title);
+                            }
+                        }
+                        let ranked = Ranked { order, cursor };
+                        // hits up to the cursor rank below all others and are dropped
+                        match &after {
+                            Some(after) if ranked.rank(after) != Ordering::Less => None,
+                            _ => Some(ranked),
+                        }
+                    }
+                })
+        });
+        let counts = (
+            Count,
+            ValueCounts::new("title"),
+            ValueCounts::new("category"),
+        );
+        let (top_docs, (total_count, per_book, per_category)) =
+            searcher.search(query, &(collector, counts))?;
+        let top = top_docs
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(ranked, doc_address)| Some((ranked?.cursor, doc_address)))
+            .collect();
+        Ok(Hits {
+            top,
+            total_count,
+            per_book,
+            per_category,
+        })
+    }
+
+    /// Runs `query` and turns the hits of `page` into results with a
+    /// snippet highlighting the terms `query` matched, as `snippets`
+    /// describes.
+    fn collect_results(
+        &self,
+        query: &dyn Query,
+        page: Page,
+        snippets: Snippets,
+    ) -> Result<SearchResponse, SearchEngineError> {
+        let searcher = self.index.reader()?.searcher();
+
+        let mut results = Vec::<SearchResult>::new();
+        let mut analyzer = self.index.tokenizer_for_field(snippets.field)?;
+        let terms = Self::snippet_terms(&searcher, query, &snippets)?;
+
+        let hits = Self::top_docs(&searcher, query, &page)?;
+
+        for (cursor, doc_address) in hits.top {
+            match searcher.doc::<TantivyDocument>(doc_address) {
+                Ok(retrieved_doc) => {
+                    let result = self.search_result(
+                        &retrieved_doc,
+                        cursor,
+                        &mut analyzer,
+                        &terms,
+                        &snippets,
+                    )?;
+                    results.push(result);
+                }
+                Err(_) => continue,
+            }
+        }
+        let mut per_book_counts: Vec<BookCount> = hits
+            .per_book
+            .into_iter()
+            .map(|(title, count)| BookCount { title, count })
+            .collect();
+        per_book_counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.title.cmp(&b.title)));
+        // hits are counted in the category of their book, and add up in
+        // those above it
+        let mut per_category = BTreeMap::<Facet, u64>::new();
+        for (encoded, count) in hits.per_category {
+            let Ok(facet) = Facet::from_encoded(encoded.into_bytes()) else {
+                continue;
+            };
+            if facet.is_root() {
+                continue;
+            }
+            let folders = facet.to_path();
+            for depth in 1..=folders.len() {
+                *per_category
+                    .entry(Facet::from_path(&folders[..depth]))
+                    .or_default() += count;
+            }
+        }
+        let per_category_counts = per_category
+            .into_iter()
+            .map(|(facet, count)| CategoryCount {
+                category: facet.to_path().join("/"),
+                count,
+            })
+            .collect();
+        Ok(SearchResponse {
+            hits: results,
+            total_count: hits.total_count as u64,
+            per_book_counts,
+            per_category_counts,
+        })
+    }
+
+    /// The result for `retrieved_doc`, with a snippet of its text
+    /// highlighting `terms` as `snippets` describes.
+    fn search_result(
+        &self,
+        retrieved_doc: &TantivyDocument,
+        cursor: SearchCursor,
+        analyzer: &mut TextAnalyzer,
+        terms: &SnippetTerms,
+        snippets: &Snippets,
+    ) -> Result<SearchResult, SearchEngineError> {
+        let schema = &self.schema;
+        let title_field = schema.get_field("title")?;
+        let text_field = schema.get_field("text")?;
+        let id_field = schema.get_field("id")?;
+        let segment_field = schema.get_field("segment")?;
+        let is_pdf_field = schema.get_field("isPdf")?;
+        let file_path_field = schema.get_field("filePath")?;
+        let title = retrieved_doc
+            .get_first(title_field)
+            .and_then(|v| match v {
+                OwnedValue::Str(s) => Some(s.clone()),
+                _ => None,
+            })
+            .unwrap_or_default();
+        let text = retrieved_doc
+            .get_first(text_field)
+            .and_then(|v| match v {
+                OwnedValue::Str(s) => Some(s.clone()),
+                _ => None,
+            })
+            .unwrap_or_default();
+        let id = retrieved_doc
+            .get_first(id_field)
+            .and_then(|v| match v {
+                OwnedValue::U64(y) => Some(*y),
+                _ => None,
+            })
+            .unwrap_or_default();
+        let segment = retrieved_doc
+            .get_first(segment_field)
+            .and_then(|v| match v {
+                OwnedValue::U64(y) => Some(*y),
+                _ => None,
+            })
+            .unwrap_or_default();
+        let is_pdf = retrieved_doc
+            .get_first(is_pdf_field)
+            .and_then(|v| match v {
+                OwnedValue::Bool(y) => Some(*y),
+                _ => None,
+            })
+            .unwrap_or_default();
+        let file_path = retrieved_doc
+            .get_first(file_path_field)
+            .and_then(|v| match v {
+                OwnedValue::Str(s) => Some(s.clone()),
+                _ => None,
+            })
+            .unwrap_or_default();
+        // snippets are cut from the text without its markup, which tags and
+        // entities would otherwise be cut through
+        let plain = analysis::plain_text(&text);
+        let fragments = snippet::fragments(
+            &text,
+            &plain,
+            analyzer,
+            &|term| terms.score(term),
+            &snippets.options,
+        );
+        // queries without terms to highlight, such as ranges, have no snippet
+        let (text, highlights) = if fragments.is_empty() {
+            (plain.as_str().to_string(), Vec::new())
+        } else {
+            Self::snippet(plain.as_str(), fragments, snippets)
+        };
+        let html = snippets
+            .options
+            .html_tags
+            .as_ref()
+            .map(|tags| highlight_html(&text, &highlights, tags));
+
+        Ok(SearchResult {
+            title,
+            text,
+            highlights,
+            html,
+            id,
+            segment,
+            is_pdf,
+            file_path,
+            cursor,
+            preceding: Vec::new(),
+            following: Vec::new(),
+        })
+    }
+
+    /// The terms highlighted in snippets: those of `query` in the snippet
+    /// field and those its fuzzy terms match, weighted like
+    /// `SnippetGenerator::create` does so rarer terms pick the fragments.
+    fn snippet_terms(
+        searcher: &Searcher,
+        query: &dyn Query,
+        snippets: &Snippets,
+    ) -> Result<SnippetTerms, SearchEngineError> {
+        let mut terms = Vec::new();
+        matchable_terms(query, &mut |term| {
+            if term.field() == snippets.field {
+                terms.push(term.clone());
+            }
+        });
+        let mut exact = BTreeMap::new();
+        for term in terms {
+            let doc_freq = searcher.doc_freq(&term)?;
+            if doc_freq > 0 {
+                exact.insert(term_text(&term), 1.0 / (1.0 + doc_freq as Score));
+            }
+        }
+        let mut fuzzy = Vec::new();
+        for (term, distance) in &snippets.fuzzy_terms {
+            let doc_freq = searcher.doc_freq(term)?;
+            let matcher = fuzzy::FuzzyMatcher::new(term, *distance);
+            fuzzy.push((matcher, 1.0 / (1.0 + doc_freq as Score)));
+        }
+        Ok(SnippetTerms { exact, fuzzy })
+    }
+
+    /// The snippet of `text` made of `fragments`, with the words they
+    /// match highlighted. For proximity searches every run of words at most
+    /// `max_distance` words apart within a fragment is highlighted as one.
+    fn snippet(
+        text: &str,
+        fragments: Vec<snippet::Fragment>,
+        snippets: &Snippets,
+    ) -> (String, Vec<Highlight>) {
+        let words = |text: &str| {
+            text.split(|c: char| !c.is_alphanumeric())
+                .filter(|word| !word.is_empty())
+                .count()
+        };
+        let mut snippet = String::new();
+        let mut highlights = Vec::new();
+        for fragment in fragments {
+            if !snippet.is_empty() {
+                snippet.push_str(&snippets.options.separator);
+            }
+            let mut spans: Vec<(Range<usize>, String)> = Vec::new();
+            for (range, term) in fragment.matches {
+                match (spans.last_mut(), snippets.proximity) {
+                    (Some((span, terms)), Some(proximity))
+                        if words(&text[span.end..range.start])
+                            <= proximity.max_distance as usize =>
+                    {
+                        span.end = range.end;
+                        terms.push(' ');
+                        terms.push_str(&term);
+                    }
+                    _ => spans.push((range, term)),
+                }
+            }
+            // offsets move from the text to the snippet
+            let start = fragment.range.start;
+            let char_offset = snippet.chars().count();
+            for (span, term) in spans {
+                let byte_start = snippet.len() + span.start - start;
+                let char_start = char_offset + text[start..span.start].chars().count();
+                highlights.push(Highlight {
+                    byte_start: byte_start as u32,
+                    byte_end: (byte_start + span.len()) as u32,
+                    char_start: char_start as u32,
+                    char_end: (char_start + text[span].chars().count()) as u32,
+                    term,
+                });
+            }
+            snippet.push_str(&text[fragment.range]);
+        }
+        (snippet, highlights)
+    }
+
+    /// Searches like `search`, sending the results found so far to `sink`
+    /// after each result, with snippets made with the default options.
+    #[allow(clippy::too_many_arguments)]
+    pub fn search_stream(
+        &mut self,
+        query: &str,
+        sink: StreamSink<Vec<SearchResult>>,
+        books: &BookFilter,
+        limit: u32,
+        fuzzy: bool,
+        proximity: Option<Proximity>,
+        options: MatchOptions,
+        order: SortOrder,
+    ) -> Result<(), SearchEngineError> {
+        let snippets =
+            self.snippets(query, fuzzy, proximity, options, SnippetOptions::default())?;
+        let query =
+            Self::create_search_query(&self.index, query, books, fuzzy, proximity, options)?;
+        let searcher = self.index.reader()?.searcher();
+        let top_docs = Self::top_docs(&searcher, &*query, &Page::first(limit, order))?.top;
+        let terms = Self::snippet_terms(&searcher, &*query, &snippets)?;
+        let mut analyzer = self.index.tokenizer_for_field(snippets.field)?;
+        let mut results = Vec::<SearchResult>::new();
+
+        for (cursor, doc_address) in top_docs {
+            match searcher.doc::<TantivyDocument>(doc_address) {
+                Ok(retrieved_doc) => {
+                    let result = self.search_result(
+                        &retrieved_doc,
+                        cursor,
+                        &mut analyzer,
+                        &terms,
+                        &snippets,
+                    )?;
+                    results.push(result);
+                    if sink.add(results.clone()).is_err() {
+                        break;
+                    }
+                }
+                Err(_) => continue,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Calls `visitor` with the terms of `query` a match may hold, unlike
+/// `Query::query_terms`, which also visits those of the clauses it must not
+/// match.
+fn matchable_terms(query: &dyn Query, visitor: &mut dyn FnMut(&Term)) {
+    match query.downcast_ref::<BooleanQuery>() {
+        Some(boolean_query) => {
+            for (occur, clause) in boolean_query.clauses() {
+                if *occur != Occur::MustNot {
+                    matchable_terms(clause.as_ref(), visitor);
+                }
+            }
+        }
+        None => query.query_terms(&mut |term, _| visitor(term)),
+    }
+}
+
+/// The terms `text` analyzes to in the text field `field` with the query
+/// analyzers `tokenizers`.
+fn query_terms(
+    schema: &Schema,
+    tokenizers: &TokenizerManager,
+    field: Field,
+    text: &str,
+) -> Vec<Term> {
+    let tokenizer = match schema.get_field_entry(field).field_type() {
+        FieldType::Str(text_options) => text_options.get_indexing_options().unwrap().tokenizer(),
+        _ => unreachable!("words are only looked up in text fields"),
+    };
+    let mut terms = Vec::new();
+    tokenizers
+        .get(tokenizer)
+        .unwrap()
+        .token_stream(text)
+        .process(&mut |token| terms.push(Term::from_field_text(field, &token.text)));
+    terms
+}
+
+impl QueryNode {
+    fn new(kind: QueryNodeKind, occur: Occur, field: &str, text: &str) -> Self {
+        QueryNode {
+            kind,
+            occur: occur.into(),
+            field: field.to_string(),
+            text: text.to_string(),
+            terms: Vec::new(),
+            distance: 0,
+            ordered: false,
+            boost: 1.0,
+            fuzzy_expansions: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+}
+
+impl From<Occur> for ClauseOccur {
+    fn from(occur: Occur) -> Self {
+        match occur {
+            Occur::Should => ClauseOccur::Should,
+            Occur::Must => ClauseOccur::Must,
+            Occur::MustNot => ClauseOccur::MustNot,
+        }
+    }
+}
+
+/// Describes the parts of a query the way `create_search_query` builds them
+/// with the query parser, for `explain_query`.
+struct QueryExplainer {
+    schema: Schema,
+    tokenizers: TokenizerManager,
+    searcher: Searcher,
+    /// The fields words are looked up in when the query names none.
+    fields: Vec<Field>,
+    fuzzy: bool,
+}
+
+impl QueryExplainer {
+    fn ast_node(&self, ast: &UserInputAst, occur: Occur) -> Result<QueryNode, SearchEngineError> {
+        match ast {
+            UserInputAst::Clause(clauses) => {
+                // fuzzy searches require every word, others any of them
+                let default_occur = if self.fuzzy {
+                    Occur::Must
+                } else {
+                    Occur::Should
+                };
+                let mut node = QueryNode::new(QueryNodeKind::Group, occur, "", &ast_text(ast));
+                for (clause_occur, clause) in clauses {
+                    let clause_occur = clause_occur.unwrap_or(default_occur);
+                    node.children.push(self.ast_node(clause, clause_occur)?);
+                }
+                Ok(node)
+            }
+            UserInputAst::Boost(ast, boost) => {
+                let mut node = self.ast_node(ast, occur)?;
+                node.boost *= *boost as f32;
+                Ok(node)
+            }
+            UserInputAst::Leaf(leaf) => self.leaf_node(leaf, occur),
+        }
+    }
+
+    fn leaf_node(
+        &self,
+        leaf: &UserInputLeaf,
+        occur: Occur,
+    ) -> Result<QueryNode, SearchEngineError> {
+        let text = leaf_text(leaf);
+        match leaf {
+            UserInputLeaf::Literal(literal) => {
+                let fields = self.fields_named(literal.field_name.as_deref())?;
+                let nodes = fields
+                    .into_iter()
+                    .map(|field| self.literal_node(literal, field, occur))
+                    .collect::<Result<_, _>>()?;
+                Ok(Self::alternatives(nodes, occur, &literal.phrase))
+            }
+            UserInputLeaf::Range {
+                field,
+                lower,
+                upper,
+            } => {
+                let field = self.schema.get_field(field.as_deref().unwrap_or("text"))?;
+                let mut node = self.field_node(QueryNodeKind::Range, occur, field, &text);
+                for bound in [lower, upper] {
+                    node.terms.push(match bound {
+                        UserInputBound::Inclusive(bound) | UserInputBound::Exclusive(bound) => {
+                            match self.terms(field, bound).pop() {
+                                Some(term) => term,
+                                None => bound.clone(),
+                            }
+                        }
+                        UserInputBound::Unbounded => "*".to_string(),
+                    });
+                }
+                Ok(node)
+            }
+            UserInputLeaf::Set { field, elements } => {
+                let nodes = self
+                    .fields_named(field.as_deref())?
+                    .into_iter()
+                    .map(|field| QueryNode {
+                        terms: elements.clone(),
+                        ..self.field_node(QueryNodeKind::TermSet, occur, field, &text)
+                    })
+                    .collect();
+                Ok(Self::alternatives(nodes, occur, &text))
+            }
+            UserInputLeaf::Exists { field } => {
+                let field = self.schema.get_field(field)?;
+                Ok(self.field_node(QueryNodeKind::Exists, occur, field, &text))
+            }
+            UserInputLeaf::All => Ok(QueryNode::new(QueryNodeKind::All, occur, "", &text)),
+        }
+    }
+
+    /// A single word becomes a term, fuzzy in the text field of a fuzzy
+    /// search, and several words a phrase.
+    fn literal_node(
+        &self,
+        literal: &UserInputLiteral,
+        field: Field,
+        occur: Occur,
+    ) -> Result<QueryNode, SearchEngineError> {
+        let mut node = self.field_node(QueryNodeKind::Term, occur, field, &literal.phrase);
+        if !matches!(
+            self.schema.get_field_entry(field).field_type(),
+            FieldType::Str(_)
+        ) {
+            node.terms.push(literal.phrase.clone());
+            return Ok(node);
+        }
+        let terms = query_terms(&self.schema, &self.tokenizers, field, &literal.phrase);
+        match terms.len() {
+            0 => node.kind = QueryNodeKind::Nothing,
+            1 if self.fuzzy && node.field == "text" => {
+                node.kind = QueryNodeKind::Fuzzy;
+                node.distance = FUZZY_DISTANCE as u32;
+                node.fuzzy_expansions =
+                    fuzzy::expansions(&self.searcher, &terms[0], FUZZY_DISTANCE)?;
+            }
+            1 => {}
+            _ => {
+                node.kind = QueryNodeKind::Phrase;
+                node.distance = literal.slop;
+            }
+        }
+        node.terms = terms.iter().map(term_text).collect();
+        Ok(node)
+    }
+
+    /// The words of a proximity search, which are taken as typed.
+    fn proximity_node(
+        &self,
+        search_term: &str,
+        proximity: Proximity,
+    ) -> Result<QueryNode, SearchEngineError> {
+        let text = analysis::normalize_query(search_term);
+        let nodes = self
+            .fields
+            .iter()
+            .map(|field| {
+                let terms = self.terms(*field, &text);
+                let kind = match terms.len() {
+                    0 => QueryNodeKind::Nothing,
+                    1 => QueryNodeKind::Term,
+                    _ => QueryNodeKind::Proximity,
+                };
+                QueryNode {
+                    terms,
+                    distance: proximity.max_distance,
+                    ordered: proximity.ordered,
+                    ..self.field_node(kind, Occur::Must, *field, search_term)
+                }
+            })
+            .collect();
+        Ok(Self::alternatives(nodes, Occur::Must, search_term))
+    }
+
+    /// A node for `field`, weighted as the query parser weighs it.
+    fn field_node(&self, kind: QueryNodeKind, occur: Occur, field: Field, text: &str) -> QueryNode {
+        let name = self.schema.get_field_name(field);
+        let mut node = QueryNode::new(kind, occur, name, text);
+        if name == "skeleton" {
+            node.boost = SKELETON_BOOST;
+        }
+        node
+    }
+
+    /// `nodes` looking up the same text in different fields, any of which
+    /// may match.
+    fn alternatives(mut nodes: Vec<QueryNode>, occur: Occur, text: &str) -> QueryNode {
+        if nodes.len() == 1 {
+            return nodes.remove(0);
+        }
+        for node in &mut nodes {
+            node.occur = ClauseOccur::Should;
+        }
+        QueryNode {
+            children: nodes,
+            ..QueryNode::new(QueryNodeKind::Group, occur, "", text)
+        }
+    }
+
+    fn fields_named(&self, name: Option<&str>) -> Result<Vec<Field>, SearchEngineError> {
+        Ok(match name {
+            Some(name) => vec![self.schema.get_field(name)?],
+            None => self.fields.clone(),
+        })
+    }
+
+    fn terms(&self, field: Field, text: &str) -> Vec<String> {
+        let terms = query_terms(&self.schema, &self.tokenizers, field, text);
+        terms.iter().map(term_text).collect()
+    }
+}
+
+/// Adds the words of `ast` a fuzzy search looks up within typos to `words`:
+/// those of the text field, outside the clauses it must not match.
+fn fuzzy_literals(ast: &UserInputAst, words: &mut Vec<String>) {
+    match ast {
+        UserInputAst::Clause(clauses) => {
+            for (occur, clause) in clauses {
+                if *occur != Some(Occur::MustNot) {
+                    fuzzy_literals(clause, words);
+                }
+            }
+        }
+        UserInputAst::Boost(ast, _) => fuzzy_literals(ast, words),
+        UserInputAst::Leaf(leaf) => {
+            if let UserInputLeaf::Literal(literal) = leaf.as_ref() {
+                if matches!(literal.field_name.as_deref(), None | Some("text")) {
+                    words.push(literal.phrase.clone());
+                }
+            }
+        }
+    }
+}
+
+/// `ast` written back as query text.
+fn ast_text(ast: &UserInputAst) -> String {
+    match ast {
+        UserInputAst::Clause(clauses) => {
+            let clauses: Vec<String> = clauses
+                .iter()
+                .map(|(occur, clause)| {
+                    let text = match clause {
+                        UserInputAst::Clause(_) => format!("({})", ast_text(clause)),
+                        _ => ast_text(clause),
+                    };
+                    match occur {
+                        Some(Occur::Must) => format!("+{text}"),
+                        Some(Occur::MustNot) => format!("-{text}"),
+                        _ => text,
+                    }
+                })
+                .collect();
+            clauses.join(" ")
+        }
+        UserInputAst::Boost(ast, boost) => match **ast {
+            UserInputAst::Clause(_) => format!("({})^{boost}", ast_text(ast)),
+            _ => format!("{}^{boost}", ast_text(ast)),
+        },
+        UserInputAst::Leaf(leaf) => leaf_text(leaf),
+    }
+}
+
+/// `leaf` written back as query text.
+fn leaf_text(leaf: &UserInputLeaf) -> String {
+    let field = |name: &Option<String>| match name {
+        Some(name) => format!("{name}:"),
+        None => String::new(),
+    };
+    match leaf {
+        UserInputLeaf::Literal(literal) => {
+            let phrase = match literal.delimiter {
+                Delimiter::DoubleQuotes => format!("\"{}\"", literal.phrase),
+                Delimiter::SingleQuotes => format!("'{}'", literal.phrase),
+                Delimiter::None => literal.phrase.clone(),
+            };
+            let slop = match literal.slop {
+                0 => String::new(),
+                slop => format!("~{slop}"),
+            };
+            format!("{}{phrase}{slop}", field(&literal.field_name))
+        }
+        UserInputLeaf::Range {
+            field: name,
+            lower,
+            upper,
+        } => {
+            let (open, lower) = match lower {
+                UserInputBound::Inclusive(bound) => ('[', bound.as_str()),
+                UserInputBound::Exclusive(bound) => ('{', bound.as_str()),
+                UserInputBound::Unbounded => ('{', "*"),
+            };
+            let (close, upper) = match upper {
+                UserInputBound::Inclusive(bound) => (']', bound.as_str()),
+                UserInputBound::Exclusive(bound) => ('}', bound.as_str()),
+                UserInputBound::Unbounded => ('}', "*"),
+            };
+            format!("{}{open}{lower} TO {upper}{close}", field(name))
+        }
+        UserInputLeaf::Set {
+            field: name,
+            elements,
+        } => format!("{}IN [{}]", field(name), elements.join(" ")),
+        UserInputLeaf::Exists { field } => format!("{field}:*"),
+        UserInputLeaf::All => "*".to_string(),
+    }
+}
+
+/// The hits of a search on a page, and the number of hits overall.
+struct Hits {
+    top: Vec<(SearchCursor, DocAddress)>,
+    total_count: usize,
+    /// Hits by book title.
+    per_book: HashMap<String, u64>,
+    /// Hits by the category of their book, encoded.
+    per_category: HashMap<String, u64>,
+}
+
+/// How to make the snippets of the results of a search.
+struct Snippets {
+    /// The field whose analyzer finds the matched words in stored text.
+    field: Field,
+    proximity: Option<Proximity>,
+    /// The terms of `field` for the fuzzy words of the query, each with the
+    /// typos it tolerates. Their matches are found in the text of each hit,
+    /// since the query does not list them.
+    fuzzy_terms: Vec<(Term, u8)>,
+    options: SnippetOptions,
+}
+
+/// The terms whose words snippets highlight, each with its score.
+struct SnippetTerms {
+    exact: BTreeMap<String, Score>,
+    fuzzy: Vec<(fuzzy::FuzzyMatcher, Score)>,
+}
+
+impl SnippetTerms {
+    /// The score of `term`, if snippets highlight its words.
+    fn score(&self, term: &str) -> Option<Score> {
+        self.exact.get(term).copied().or_else(|| {
+            let mut fuzzy = self.fuzzy.iter();
+            fuzzy
+                .find(|(matcher, _)| matcher.matches(term))
+                .map(|(_, score)| *score)
+        })
+    }
+}
+
+/// Which hits of a search to return, and in which order.
+struct Page {
+    order: SortOrder,
+    /// Hits skipped, counted from `after` when given.
+    offset: u32,
+    limit: u32,
+    after: Option<SearchCursor>,
+}
+
+impl Page {
+    fn first(limit: u32, order: SortOrder) -> Self {
+        Page {
+            order,
+            offset: 0,
+            limit,
+            after: None,
+        }
+    }
+}
+
+/// A hit ordered so that the collector, which keeps the greatest hits,
+/// keeps those coming first in `order`.
+#[derive(Clone)]
+struct Ranked {
+    order: SortOrder,
+    cursor: SearchCursor,
+}
+
+impl Ranked {
+    /// `Greater` when this hit comes before the one at `other`. Ties are
+    /// broken by index address, earliest first.
+    fn rank(&self, other: &SearchCursor) -> Ordering {
+        let (a, b) = (&self.cursor, other);
+        let primary = match self.order {
+            SortOrder::Relevance => a.score.total_cmp(&b.score),
+            SortOrder::LibraryOrder => b.id.cmp(&a.id),
+            SortOrder::LibraryOrderDescending => a.id.cmp(&b.id),
+            SortOrder::Title => (&b.title, b.segment).cmp(&(&a.title, a.segment)),
+            SortOrder::TitleDescending => (&a.title, a.segment).cmp(&(&b.title, b.segment)),
+        };
+        primary.then_with(|| (b.segment_ord, b.doc_id).cmp(&(a.segment_ord, a.doc_id)))
+    }
+}
+
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.rank(&other.cursor) == Ordering::Equal
+    }
+}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.rank(&other.cursor))
+    }
+}
+
+/// The facet of the category at `path`, a path of folders separated by
+/// `/` or `\\`.
+fn category_facet(path: &str) -> Facet {
+    Facet::from_path(path.split(['/', '\\']).filter(|folder| !folder.is_empty()))
+}
+
+/// `text` as HTML, with `tags` around its `highlights`.
+fn highlight_html(text: &str, highlights: &[Highlight], tags: &HighlightTags) -> String {
+    let mut html = String::new();
+    let mut start = 0;
+    for highlight in highlights {
+        let span = highlight.byte_start as usize..highlight.byte_end as usize;
+        html.push_str(&htmlescape::encode_minimal(&text[start..span.start]));
+        html.push_str(&tags.open);
+        html.push_str(&htmlescape::encode_minimal(&text[span.clone()]));
+        html.push_str(&tags.close);
+        start = span.end;
+    }
+    html.push_str(&htmlescape::encode_minimal(&text[start..]));
+    html
+}
+
+/// The text of a term of a text field.
+fn term_text(term: &Term) -> String {
+    term.value().as_str().unwrap_or_default().to_string()
+}
+
+/// Loads the abbreviation dictionary used to expand queries, replacing the
+/// one loaded before, and returns its number of entries.
+///
+/// The file is UTF-8 text with one abbreviation per line, followed by `=` and
+/// its expansions separated by `|`, e.g. `רש"י = רבי שלמה יצחקי`. Blank
+/// lines and lines starting with `#` are ignored.
+pub fn load_abbreviations(path: &str) -> Result<u32, SearchEngineError> {
+    Ok(analysis::abbreviations::load(path)? as u32)
+}
+
+/// Loads a list of known roots, one per line, that limits the candidate roots
+/// indexed for root matching, and returns its number of roots. Without it
+/// roots are derived by rule alone. Only documents added afterwards are
+/// affected, so load it before indexing.
+pub fn load_root_lexicon(path: &str) -> Result<u32, SearchEngineError> {
+    Ok(analysis::roots::load_lexicon(path)? as u32)
+}
+
+/// The value of a Hebrew numeral such as "קכ"ג" or "קכג", or of a number
+/// written in digits, e.g. for filtering by chapter or page. Returns `None`
+/// when `text` is not a number.
+#[flutter_rust_bridge::frb(sync)]
+pub fn parse_hebrew_numeral(text: &str) -> Option<u32> {
+    analysis::numerals::parse(&analysis::normalize_query(text.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An engine over a new index in the temporary directory, named after
+    /// the test using it, whose single book has `texts` as its segments.
+    fn engine(name: &str, texts: &[&str]) -> SearchEngine {
+        library(name, &[("ספר", texts)])
+    }
+
+    /// An engine like [`engine`] over several books, given by title and
+    /// segments, in library order.
+    fn library(name: &str, books: &[(&str, &[&str])]) -> SearchEngine {
+        let file_paths: Vec<String> = books
+            .iter()
+            .map(|(title, _)| format!("ספרים/{title}"))
+            .collect();
+        let books: Vec<(&str, &[&str])> = file_paths
+            .iter()
+            .zip(books)
+            .map(|(file_path, (_, texts))| (file_path.as_str(), *texts))
+            .collect();
+        shelves(name, &books)
+    }
+
+    /// An engine like [`library`] with books given by file path, titled
+    /// after their file and in the categories of their folders.
+    fn shelves(name: &str, books: &[(&str, &[&str])]) -> SearchEngine {
+        let path =
+            std::env::temp_dir().join(format!("search_engine_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        let mut engine = SearchEngine::new(path.to_str().unwrap()).unwrap();
+        let mut id = 0;
+        for (file_path, texts) in books {
+            let title = file_path.rsplit('/').next().unwrap();
+            for (segment, text) in texts.iter().enumerate() {
+                engine
+                    .add_document(id, title, text, segment as u64, false, file_path)
+                    .unwrap();
+                id += 1;
+            }
+        }
+        engine.commit().unwrap();
+        engine
+    }
+
+    /// The segments matching `query` within `proximity`, in library order.
+    fn near(engine: &mut SearchEngine, query: &str, proximity: Proximity) -> Vec<u64> {
+        let books = BookFilter {
+            all_books: true,
+            ..Default::default()
+        };
+        let response = engine
+            .search(
+                query,
+                &books,
+                100,
+                false,
+                Some(proximity),
+                MatchOptions::default(),
+                SnippetOptions::default(),
+                0,
+                SortOrder::LibraryOrder,
+                0,
+                None,
+            )
+            .unwrap();
+        response.hits.iter().map(|hit| hit.segment).collect()
+    }
+
+    /// Two words with 0 to 3 words between them, then in reverse order
+    /// with 0 and 1 words between them.
+    const PAIRS: &[&str] = &[
+        "אור חושך",
+        "אור ערב חושך",
+        "אור ערב בקר חושך",
+        "אור ערב בקר יום חושך",
+        "חושך אור",
+        "חושך ערב אור",
+    ];
+
+    #[test]
+    fn ordered_proximity_allows_up_to_max_distance_words_between() {
+        let mut engine = engine("ordered_proximity", PAIRS);
+        for max_distance in 0..4 {
+            let proximity = Proximity {
+                max_distance,
+                ordered: true,
+            };
+            let expected: Vec<u64> = (0..=max_distance as u64).collect();
+            assert_eq!(near(&mut engine, "אור חושך", proximity), expected);
+        }
+    }
+
+    #[test]
+    fn unordered_proximity_allows_either_order() {
+        let mut engine = engine("unordered_proximity", PAIRS);
+        let proximity = |max_distance| Proximity {
+            max_distance,
+            ordered: false,
+        };
+        assert_eq!(near(&mut engine, "אור חושך", proximity(0)), [0, 4]);
+        assert_eq!(near(&mut engine, "אור חושך", proximity(1)), [0, 1, 4, 5]);
+        assert_eq!(
+            near(&mut engine, "אור חושך", proximity(3)),
+            [0, 1, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn ordered_proximity_of_several_words_bounds_every_gap() {
+        let mut engine = engine(
+            "ordered_gaps",
+            &[
+                "אור חושך ערב",
+                "אור יום חושך ערב",
+                "אור יום חושך בקר ערב",
+                "אור יום לילה חושך ערב",
+                "אור ערב חושך",
+            ],
+        );
+        let proximity = |max_distance| Proximity {
+            max_distance,
+            ordered: true,
+        };
+        assert_eq!(near(&mut engine, "אור חושך ערב", proximity(0)), [0]);
+        assert_eq!(near(&mut engine, "אור חושך ערב", proximity(1)), [0, 1, 2]);
+        assert_eq!(
+            near(&mut engine, "אור חושך ערב", proximity(2)),
+            [0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn proximity_searches_are_bounded() {
+        let mut engine = engine("proximity_bounds", &["א ב ג ד ה ו ז"]);
+        let proximity = |max_distance| Proximity {
+            max_distance,
+            ordered: true,
+        };
+        let invalid = |words: &str, proximity: Proximity| {
+            let books = BookFilter::default();
+            let options = MatchOptions::default();
+            let query = SearchEngine::create_search_query(
+                &engine.index,
+                words,
+                &books,
+                false,
+                Some(proximity),
+                options,
+            );
+            matches!(
+                query,
+                Err(SearchEngineError {
+                    kind: SearchEngineErrorKind::InvalidQuery,
+                    ..
+                })
+            )
+        };
+        assert!(invalid("א ב ג ד ה ו ז", proximity(0)));
+        assert!(invalid("א ב ג ד ה ו", proximity(4)));
+        assert!(invalid("א ב ג", proximity(u32::MAX)));
+        assert!(!invalid("א ב", proximity(u32::MAX)));
+        assert_eq!(near(&mut engine, "א ב ג ד ה ו", proximity(3)), [0]);
+    }
+
+    fn cursor(score: f32, id: u64, title: &str, segment: u64, doc_id: u32) -> SearchCursor {
+        SearchCursor {
+            score,
+            id,
+            title: title.to_string(),
+            segment,
+            segment_ord: 0,
+            doc_id,
+        }
+    }
+
+    #[test]
+    fn hits_rank_by_order_then_by_address() {
+        let ranked = |order, cursor| Ranked { order, cursor };
+        let first = cursor(2.0, 5, "בראשית", 3, 7);
+        let second = cursor(1.0, 9, "שמות", 1, 2);
+        let orders = [
+            (SortOrder::Relevance, Ordering::Greater),
+            (SortOrder::LibraryOrder, Ordering::Greater),
+            (SortOrder::LibraryOrderDescending, Ordering::Less),
+            (SortOrder::Title, Ordering::Greater),
+            (SortOrder::TitleDescending, Ordering::Less),
+        ];
+        for (order, expected) in orders {
+            assert_eq!(
+                ranked(order, first.clone()).rank(&second),
+                expected,
+                "{order:?}"
+            );
+            assert_eq!(
+                ranked(order, second.clone()).rank(&first),
+                expected.reverse()
+            );
+        }
+        let same_segment = cursor(1.0, 9, "בראשית", 3, 8);
+        let by_title = ranked(SortOrder::Title, first.clone());
+        assert_eq!(by_title.rank(&same_segment), Ordering::Greater);
+        let tie = cursor(2.0, 5, "בראשית", 3, 7);
+        assert_eq!(
+            ranked(SortOrder::Relevance, first).rank(&tie),
+            Ordering::Equal
+        );
+    }
+
+    /// Books whose segments all hold the word searched for in
+    /// [`search_page`], as often as it takes for relevance to vary.
+    const BOOKS: &[(&str, &[&str])] = &[
+        ("שמות", &["אור", "אור ויום", "אור ויום ולילה"]),
+        ("בראשית", &["אור אור", "אור ערב ובקר", "ערב אור"]),
+        ("ויקרא", &["אור", "אור אור אור"]),
+    ];
+
+    /// The ids of a page of the results for "אור" in `order`.
+    fn search_page(
+        engine: &mut SearchEngine,
+        order: SortOrder,
+        limit: u32,
+        offset: u32,
+        after: Option<SearchCursor>,
+    ) -> (Vec<u64>, Option<SearchCursor>) {
+        let books = BookFilter {
+            all_books: true,
+            ..Default::default()
+        };
+        let response = engine
+            .search(
+                "אור",
+                &books,
+                limit,
+                false,
+                None,
+                MatchOptions::default(),
+                SnippetOptions::default(),
+                0,
+                order,
+                offset,
+                after,
+            )
+            .unwrap();
+        assert_eq!(response.total_count, 8);
+        let last = response.hits.last().map(|hit| hit.cursor.clone());
+        (response.hits.iter().map(|hit| hit.id).collect(), last)
+    }
+
+    #[test]
+    fn results_come_in_the_order_asked_for() {
+        let mut engine = library("sort_orders", BOOKS);
+        let all = |engine: &mut SearchEngine, order| search_page(engine, order, 100, 0, None).0;
+        let library_order: Vec<u64> = (0..8).collect();
+        assert_eq!(all(&mut engine, SortOrder::LibraryOrder), library_order);
+        let descending: Vec<u64> = library_order.iter().rev().copied().collect();
+        assert_eq!(
+            all(&mut engine, SortOrder::LibraryOrderDescending),
+            descending
+        );
+        assert_eq!(all(&mut engine, SortOrder::Title), [3, 4, 5, 6, 7, 0, 1, 2]);
+        assert_eq!(
+            all(&mut engine, SortOrder::TitleDescending),
+            [2, 1, 0, 7, 6, 5, 4, 3]
+        );
+    }
+
+    #[test]
+    fn pages_follow_one_another() {
+        let mut engine = library("paging", BOOKS);
+        let orders = [
+            SortOrder::Relevance,
+            SortOrder::LibraryOrder,
+            SortOrder::LibraryOrderDescending,
+            SortOrder::Title,
+            SortOrder::TitleDescending,
+        ];
+        for order in orders {
+            let (all, _) = search_page(&mut engine, order, 100, 0, None);
+            assert_eq!(all.len(), 8);
+
+            let mut by_offset = Vec::new();
+            for offset in (0..8).step_by(3) {
+                by_offset.extend(search_page(&mut engine, order, 3, offset, None).0);
+            }
+            assert_eq!(by_offset, all, "{order:?} by offset");
+
+            let mut by_cursor = Vec::new();
+            let mut after = None;
+            loop {
+                let (page, last) = search_page(&mut engine, order, 3, 0, after);
+                by_cursor.extend(page);
+                if last.is_none() {
+                    break;
+                }
+                after = last;
+            }
+            assert_eq!(by_cursor, all, "{order:?} by cursor");
+
+            let (_, after) = search_page(&mut engine, order, 2, 0, None);
+            let (skipped, _) = search_page(&mut engine, order, 3, 1, after);
+            assert_eq!(skipped, all[3..6], "{order:?} by offset after a cursor");
+        }
+    }
+
+    /// The matches of `query` in the segments of [`engine`], by segment.
+    fn highlighted(
+        engine: &SearchEngine,
+        query: &str,
+        proximity: Option<Proximity>,
+    ) -> Vec<(u64, Vec<String>)> {
+        let segments = engine
+            .highlight_in_document(
+                query,
+                "ספר",
+                0,
+                10,
+                false,
+                proximity,
+                MatchOptions::default(),
+            )
+            .unwrap();
+        segments
+            .into_iter()
+            .map(|segment| {
+                let terms = segment
+                    .highlights
+                    .into_iter()
+                    .map(|highlight| highlight.term);
+                (segment.segment, terms.collect())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn only_segments_matching_the_query_are_highlighted() {
+        let engine = engine(
+            "highlight_in_document",
+            &[
+                "תורה משה",
+                "תורה",
+                "משה",
+                "אור ערב בקר יום חושך",
+                "אור חושך",
+            ],
+        );
+        assert_eq!(
+            highlighted(&engine, "תורה -משה", None),
+            [(1, vec!["תורה".to_string()])]
+        );
+        let proximity = Proximity {
+            max_distance: 1,
+            ordered: true,
+        };
+        let matches = highlighted(&engine, "אור חושך", Some(proximity));
+        assert_eq!(matches, [(4, vec!["אור חושך".to_string()])]);
+    }
+
+    /// The hits of a fuzzy search for `query` in [`engine`], in library order.
+    fn fuzzy_search(
+        engine: &mut SearchEngine,
+        query: &str,
+        options: MatchOptions,
+    ) -> Vec<SearchResult> {
+        let books = BookFilter {
+            all_books: true,
+            ..Default::default()
+        };
+        let response = engine
+            .search(
+                query,
+                &books,
+                1000,
+                true,
+                None,
+                options,
+                SnippetOptions::default(),
+                0,
+                SortOrder::LibraryOrder,
+                0,
+                None,
+            )
+            .unwrap();
+        response.hits
+    }
+
+    /// The segments of [`engine`] a fuzzy search for `query` finds.
+    fn fuzzy_matches(engine: &mut SearchEngine, query: &str, options: MatchOptions) -> Vec<u64> {
+        let hits = fuzzy_search(engine, query, options);
+        hits.iter().map(|hit| hit.segment).collect()
+    }
+
+    /// The highlighted parts of the text of `hit`.
+    fn highlighted_text(hit: &SearchResult) -> Vec<&str> {
+        let text = |highlight: &Highlight| {
+            &hit.text[highlight.byte_start as usize..highlight.byte_end as usize]
+        };
+        hit.highlights.iter().map(text).collect()
+    }
+
+    #[test]
+    fn fuzzy_words_do_not_match_marked_forms() {
+        let mut engine = engine(
+            "fuzzy_marks",
+            &["ושבתורה כתוב", "ה׳ שמע", "תורת משה", "תורה"],
+        );
+        let options = MatchOptions::default();
+        assert_eq!(fuzzy_matches(&mut engine, "תורה", options), [2, 3]);
+        assert!(fuzzy_matches(&mut engine, "יהוה", options).is_empty());
+        let prefixes = MatchOptions {
+            prefix_insensitive: true,
+            ..options
+        };
+        assert_eq!(fuzzy_matches(&mut engine, "תורה", prefixes), [0, 2, 3]);
+        let divine_names = MatchOptions {
+            divine_names: true,
+            ..options
+        };
+        assert_eq!(fuzzy_matches(&mut engine, "יהוה", divine_names), [1]);
+    }
+
+    #[test]
+    fn fuzzy_searches_are_only_by_words() {
+        let engine = engine("fuzzy_invalid", &["שלום עליכם"]);
+        let invalid = |proximity: Option<Proximity>, options: MatchOptions| {
+            let books = BookFilter::default();
+            let query = SearchEngine::create_search_query(
+                &engine.index,
+                "שלים עליכם",
+                &books,
+                true,
+                proximity,
+                options,
+            );
+            matches!(
+                query,
+                Err(SearchEngineError {
+                    kind: SearchEngineErrorKind::InvalidQuery,
+                    ..
+                })
+            )
+        };
+        let proximity = Proximity {
+            max_distance: 1,
+            ordered: false,
+        };
+        let by_root = MatchOptions {
+            by_root: true,
+            ..Default::default()
+        };
+        assert!(!invalid(None, MatchOptions::default()));
+        assert!(invalid(Some(proximity), MatchOptions::default()));
+        assert!(invalid(None, by_root));
+    }
+
+    #[test]
+    fn every_fuzzy_hit_is_highlighted() {
+        // more words a typo away from the one searched for than are listed
+        let letters = "אבגדהזחטיכלמנסעפצקרשת".chars();
+        let words: Vec<String> = letters
+            .flat_map(|letter| {
+                [
+                    format!("{letter}ורה"),
+                    format!("ת{letter}רה"),
+                    format!("תו{letter}ה"),
+                ]
+            })
+            .collect();
+        let mut texts: Vec<String> = words.iter().map(|word| format!("כתוב {word}")).collect();
+        texts.push("ותורת משה".to_string());
+        let texts: Vec<&str> = texts.iter().map(String::as_str).collect();
+        let mut engine = engine("fuzzy_highlights", &texts);
+
+        let hits = fuzzy_search(&mut engine, "תורה", MatchOptions::default());
+        assert!(hits.len() > fuzzy::MAX_EXPANSIONS);
+        for hit in &hits {
+            let word = hit.text.split(' ').nth(1).unwrap();
+            assert_eq!(highlighted_text(hit), [word], "{}", hit.text);
+        }
+
+        let prefixes = MatchOptions {
+            prefix_insensitive: true,
+            ..Default::default()
+        };
+        let hits = fuzzy_search(&mut engine, "תורה", prefixes);
+        let last = hits.last().unwrap();
+        assert_eq!(last.text, "ותורת משה");
+        assert_eq!(highlighted_text(last), ["ותורת"]);
+
+        let query = SearchQuery {
+            clauses: vec![QueryClause {
+                words: vec!["תורה".to_string()],
+                occur: ClauseOccur::Must,
+                fuzziness: 1,
+                proximity: None,
+            }],
+            filters: Vec::new(),
+        };
+        let books = BookFilter {
+            all_books: true,
+            ..Default::default()
+        };
+        let response = engine
+            .search_structured(
+                &query,
+                &books,
+                1000,
+                MatchOptions::default(),
+                SnippetOptions::default(),
+                SortOrder::LibraryOrder,
+            )
+            .unwrap();
+        assert_eq!(response.hits.len(), hits.len() - 1);
+        assert!(response.hits.iter().all(|hit| hit.highlights.len() == 1));
+    }
+
+    #[test]
+    fn fragments_are_joined_by_the_separator() {
+        let mut engine = engine(
+            "snippet_fragments",
+            &["אור אחד שתים שלש ארבע חמש שש שבע שמונה חושך"],
+        );
+        let books = BookFilter {
+            all_books: true,
+            ..Default::default()
+        };
+        let snippet_options = SnippetOptions {
+            max_chars: 10,
+            fragments: 2,
+            separator: " | ".to_string(),
+            ..Default::default()
+        };
+        let response = engine
+            .search(
+                "אור חושך",
+                &books,
+                10,
+                false,
+                None,
+                MatchOptions::default(),
+                snippet_options,
+                0,
+                SortOrder::LibraryOrder,
+                0,
+                None,
+            )
+            .unwrap();
+        let hit = &response.hits[0];
+        assert_eq!(hit.text, "אור אחד | שמונה חושך");
+        assert_eq!(highlighted_text(hit), ["אור", "חושך"]);
+        let chars: Vec<(u32, u32)> = hit
+            .highlights
+            .iter()
+            .map(|highlight| (highlight.char_start, highlight.char_end))
+            .collect();
+        assert_eq!(chars, [(0, 3), (16, 20)]);
+    }
+
+    #[test]
+    fn snippets_are_plain_text() {
+        let mut engine = engine(
+            "plain_snippets",
+            &["<b>ויאמר</b> משה<br>אל <small>אהרן</small> &amp; בניו"],
+        );
+        let books = BookFilter {
+            all_books: true,
+            ..Default::default()
+        };
+        let snippet_options = SnippetOptions {
+            max_chars: 12,
+            html_tags: Some(HighlightTags {
+                open: "<em>".to_string(),
+                close: "</em>".to_string(),
+            }),
+            ..Default::default()
+        };
+        let response = engine
+            .search(
+                "אהרן",
+                &books,
+                10,
+                false,
+                None,
+                MatchOptions::default(),
+                snippet_options,
+                0,
+                SortOrder::LibraryOrder,
+                0,
+                None,
+            )
+            .unwrap();
+        let hit = &response.hits[0];
+        assert_eq!(hit.text, "אל אהרן &");
+        assert_eq!(highlighted_text(hit), ["אהרן"]);
+        assert_eq!(hit.html.as_deref(), Some("אל <em>אהרן</em> &amp;"));
+    }
+
+    /// The ids of the results of `query` over all books, in library order.
+    fn structured(engine: &mut SearchEngine, query: &SearchQuery) -> Vec<u64> {
+        let books = BookFilter {
+            all_books: true,
+            ..Default::default()
+        };
+        let response = engine
+            .search_structured(
+                query,
+                &books,
+                100,
+                MatchOptions::default(),
+                SnippetOptions::default(),
+                SortOrder::LibraryOrder,
+            )
+            .unwrap();
+        response.hits.iter().map(|hit| hit.id).collect()
+    }
+
+    fn word(word: &str, occur: ClauseOccur) -> QueryClause {
+        QueryClause {
+            words: vec![word.to_string()],
+            occur,
+            fuzziness: 0,
+            proximity: None,
+        }
+    }
+
+    #[test]
+    fn filters_restrict_the_words_of_structured_queries() {
+        let mut engine = library(
+            "structured_filters",
+            &[
+                ("בראשית", &["תורה", "משה", "תורה משה"]),
+                ("שמות", &["תורה", "אהרן"]),
+            ],
+        );
+        let title = |exclude| FieldFilter {
+            field: FilterField::Title,
+            values: vec!["בראשית".to_string()],
+            exclude,
+        };
+        let should = SearchQuery {
+            clauses: vec![word("תורה", ClauseOccur::Should)],
+            filters: Vec::new(),
+        };
+        assert_eq!(structured(&mut engine, &should), [0, 2, 3]);
+        let should_in_title = SearchQuery {
+            filters: vec![title(false)],
+            ..should.clone()
+        };
+        assert_eq!(structured(&mut engine, &should_in_title), [0, 2]);
+
+        let must_not = SearchQuery {
+            clauses: vec![word("תורה", ClauseOccur::MustNot)],
+            filters: vec![title(false)],
+        };
+        assert_eq!(structured(&mut engine, &must_not), [1]);
+        let must_not_elsewhere = SearchQuery {
+            filters: vec![title(true)],
+            ..must_not.clone()
+        };
+        assert_eq!(structured(&mut engine, &must_not_elsewhere), [4]);
+    }
+
+    /// The segments of [`engine`] a search for `query` finds.
+    fn found(engine: &mut SearchEngine, query: &str) -> Vec<u64> {
+        let books = BookFilter {
+            all_books: true,
+            ..Default::default()
+        };
+        let response = engine
+            .search(
+                query,
+                &books,
+                100,
+                false,
+                None,
+                MatchOptions::default(),
+                SnippetOptions::default(),
+                0,
+                SortOrder::LibraryOrder,
+                0,
+                None,
+            )
+            .unwrap();
+        response.hits.iter().map(|hit| hit.segment).collect()
+    }
+
+    #[test]
+    fn numbers_match_in_letters_and_digits() {
+        let mut engine = engine(
+            "numbers",
+            &["פרק 15", "פרק ט״ו", "פרק כ׳", "פרק 25", "טו באב"],
+        );
+        assert_eq!(found(&mut engine, "15"), [0, 1]);
+        assert_eq!(found(&mut engine, "ט\"ו"), [0, 1]);
+        assert_eq!(found(&mut engine, "ט״ו"), [0, 1]);
+        assert_eq!(found(&mut engine, "טו"), [1, 4]);
+        assert_eq!(found(&mut engine, "[10 TO 20]"), [0, 1, 2]);
+        assert_eq!(found(&mut engine, "[י TO כ]"), [0, 1, 2]);
+        assert_eq!(found(&mut engine, "{15 TO 25]"), [2, 3]);
+    }
+
+    /// The segments before and after each hit of a search for `query` with
+    /// `context` segments of context, by the segment of the hit.
+    fn neighbors(
+        engine: &mut SearchEngine,
+        query: &str,
+        context: u32,
+    ) -> Vec<(u64, Vec<u64>, Vec<u64>)> {
+        let books = BookFilter {
+            all_books: true,
+            ..Default::default()
+        };
+        let response = engine
+            .search(
+                query,
+                &books,
+                100,
+                false,
+                None,
+                MatchOptions::default(),
+                SnippetOptions::default(),
+                context,
+                SortOrder::LibraryOrder,
+                0,
+                None,
+            )
+            .unwrap();
+        let segments =
+            |neighbors: &[NeighborSegment]| neighbors.iter().map(|n| n.segment).collect();
+        response
+            .hits
+            .iter()
+            .map(|hit| {
+                (
+                    hit.segment,
+                    segments(&hit.preceding),
+                    segments(&hit.following),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn neighbors_come_from_the_book_of_the_hit() {
+        let mut engine = library(
+            "neighbors",
+            &[
+                ("בראשית", &["אור", "יום", "ערב", "בקר", "אור"]),
+                ("שמות", &["לילה", "אור", "יום"]),
+            ],
+        );
+        assert_eq!(
+            neighbors(&mut engine, "אור", 2),
+            [
+                (0, vec![], vec![1, 2]),
+                (4, vec![2, 3], vec![]),
+                (1, vec![0], vec![2]),
+            ]
+        );
+        assert_eq!(neighbors(&mut engine, "ערב", 1), [(2, vec![1], vec![3])]);
+
+        // the last segment there can be has nothing after it
+        engine
+            .add_document(8, "ויקרא", "חושך", u64::MAX, false, "ספרים/ויקרא")
+            .unwrap();
+        engine.commit().unwrap();
+        assert_eq!(
+            neighbors(&mut engine, "חושך", 2),
+            [(u64::MAX, vec![], vec![])]
+        );
+        assert_eq!(
+            neighbors(&mut engine, "אור", 0),
+            [
+                (0, vec![], vec![]),
+                (4, vec![], vec![]),
+                (1, vec![], vec![])
+            ]
+        );
+    }
+
+    /// Books in nested categories, one of whose names starts another's.
+    const SHELVES: &[(&str, &[&str])] = &[
+        ("אוצריא/תנך/תורה/בראשית", &["אור"]),
+        ("אוצריא/תנך/נביאים/יהושע", &["אור"]),
+        ("אוצריא/תלמוד/ברכות", &["אור", "אור"]),
+        ("אוצריא/תנכים/שמואל", &["אור"]),
+    ];
+
+    /// The ids of the results for "אור" in `books`.
+    fn in_books(engine: &mut SearchEngine, books: BookFilter) -> Vec<u64> {
+        let response = engine
+            .search(
+                "אור",
+                &books,
+                100,
+                false,
+                None,
+                MatchOptions::default(),
+                SnippetOptions::default(),
+                0,
+                SortOrder::LibraryOrder,
+                0,
+                None,
+            )
+            .unwrap();
+        response.hits.iter().map(|hit| hit.id).collect()
+    }
+
+    #[test]
+    fn books_are_chosen_by_title_and_category() {
+        let mut engine = shelves("book_filters", SHELVES);
+        let strings = |values: &[&str]| values.iter().map(|v| v.to_string()).collect();
+        let filter = |include_titles: &[&str], include_categories: &[&str]| BookFilter {
+            include_titles: strings(include_titles),
+            include_categories: strings(include_categories),
+            ..Default::default()
+        };
+
+        assert!(in_books(&mut engine, BookFilter::default()).is_empty());
+        assert_eq!(in_books(&mut engine, filter(&[], &["אוצריא/תנך"])), [0, 1]);
+        assert_eq!(
+            in_books(&mut engine, filter(&[], &["/אוצריא/תנך/"])),
+            [0, 1]
+        );
+        assert_eq!(
+            in_books(&mut engine, filter(&["ברכות"], &["אוצריא/תנך/תורה"])),
+            [0, 2, 3]
+        );
+        let without_prophets = BookFilter {
+            exclude_categories: strings(&["אוצריא/תנך/נביאים"]),
+            ..filter(&[], &["אוצריא/תנך"])
+        };
+        assert_eq!(in_books(&mut engine, without_prophets), [0]);
+
+        let all_but = |exclude_titles: &[&str], exclude_categories: &[&str]| BookFilter {
+            all_books: true,
+            exclude_titles: strings(exclude_titles),
+            exclude_categories: strings(exclude_categories),
+            ..filter(&["שמואל"], &[])
+        };
+        assert_eq!(in_books(&mut engine, all_but(&[], &[])), [0, 1, 2, 3, 4]);
+        assert_eq!(
+            in_books(&mut engine, all_but(&[], &["אוצריא/תלמוד"])),
+            [0, 1, 4]
+        );
+        assert_eq!(
+            in_books(&mut engine, all_but(&["שמואל"], &["אוצריא/תנך"])),
+            [2, 3]
+        );
+    }
+
+    #[test]
+    fn counts_add_up_in_the_categories_above() {
+        let mut engine = shelves("category_counts", SHELVES);
+        let books = BookFilter {
+            all_books: true,
+            ..Default::default()
+        };
+        let response = engine
+            .search(
+                "אור",
+                &books,
+                1,
+                false,
+                None,
+                MatchOptions::default(),
+                SnippetOptions::default(),
+                0,
+                SortOrder::LibraryOrder,
+                0,
+                None,
+            )
+            .unwrap();
+        let books: Vec<(&str, u64)> = response
+            .per_book_counts
+            .iter()
+            .map(|book| (book.title.as_str(), book.count))
+            .collect();
+        assert_eq!(
+            books,
+            [("ברכות", 2), ("בראשית", 1), ("יהושע", 1), ("שמואל", 1)]
+        );
+        let categories: Vec<(&str, u64)> = response
+            .per_category_counts
+            .iter()
+            .map(|category| (category.category.as_str(), category.count))
+            .collect();
+        assert_eq!(
+            categories,
+            [
+                ("אוצריא", 5),
+                ("אוצריא/תלמוד", 2),
+                ("אוצריא/תנך", 2),
+                ("אוצריא/תנך/נביאים", 1),
+                ("אוצריא/תנך/תורה", 1),
+                ("אוצריא/תנכים", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn failures_are_told_apart_by_kind() {
+        let mut engine = engine("errors", &["תורה"]);
+        let error = engine
+            .search(
+                "(תורה",
+                &BookFilter::default(),
+                10,
+                false,
+                None,
+                MatchOptions::default(),
+                SnippetOptions::default(),
+                0,
+                SortOrder::LibraryOrder,
+                0,
+                None,
+            )
+            .err()
+            .unwrap();
+        assert_eq!(error.kind, SearchEngineErrorKind::InvalidQuery);
+        assert_eq!(error.position, Some(5));
+
+        let second = SearchEngine::new(&engine.path).err().unwrap();
+        assert_eq!(second.kind, SearchEngineErrorKind::IndexLocked);
+    }
+
+    #[test]
+    fn explanations_describe_each_part_of_the_query() {
+        let engine = engine("explain_query", &["תורה", "תורת משה"]);
+        let explanation = engine
+            .explain_query(
+                "+תורה -משה \"אור גדול\"",
+                false,
+                None,
+                MatchOptions::default(),
+            )
+            .unwrap();
+        assert!(explanation.error.is_none());
+        let parts: Vec<(QueryNodeKind, &str, Vec<String>)> = explanation
+            .root
+            .as_ref()
+            .unwrap()
+            .children
+            .iter()
+            .map(|node| {
+                let occur = match node.occur {
+                    ClauseOccur::Should => "should",
+                    ClauseOccur::Must => "must",
+                    ClauseOccur::MustNot => "must not",
+                };
+                (node.kind, occur, node.terms.clone())
+            })
+            .collect();
+        let terms = |terms: &[&str]| terms.iter().map(|term| term.to_string()).collect();
+        assert_eq!(
+            parts,
+            [
+                (QueryNodeKind::Term, "must", terms(&["תורה"])),
+                (QueryNodeKind::Term, "must not", terms(&["משה"])),
+                (QueryNodeKind::Phrase, "should", terms(&["אור", "גדול"])),
+            ]
+        );
+
+        let fuzzy = engine
+            .explain_query("תורא", true, None, MatchOptions::default())
+            .unwrap()
+            .root
+            .unwrap();
+        assert_eq!(fuzzy.kind, QueryNodeKind::Fuzzy);
+        assert_eq!(fuzzy.distance, FUZZY_DISTANCE as u32);
+        assert_eq!(fuzzy.fuzzy_expansions, ["תורה", "תורת"]);
+
+        let invalid = engine
+            .explain_query("(תורה", false, None, MatchOptions::default())
+            .unwrap();
+        assert!(invalid.root.is_none());
+        assert_eq!(invalid.error.unwrap().position, Some(5));
+    }
+
+    #[test]
+    fn only_results_have_their_score_explained() {
+        let engine = engine("explain_score", &["תורה", "משה"]);
+        let books = BookFilter {
+            all_books: true,
+            ..Default::default()
+        };
+        let explain = |id| {
+            engine
+                .explain_score("תורה", &books, false, None, MatchOptions::default(), id)
+                .unwrap()
+        };
+        assert!(explain(0).unwrap().value > 0.0);
+        assert!(explain(1).is_none());
+        assert!(explain(2).is_none());
+    }
 }
@@ -38,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.3.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -340198064;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1880642976;
 
 // Section: executor
 
@@ -79,7 +79,7 @@ fn wire__crate__api__search_engine__SearchEngine_add_document_impl(
             let api__file_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::api::search_engine::SearchEngineError>(
                     (move || {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -138,7 +138,7 @@ fn wire__crate__api__search_engine__SearchEngine_commit_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::api::search_engine::SearchEngineError>(
                     (move || {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -189,11 +189,15 @@ fn wire__crate__api__search_engine__SearchEngine_create_search_query_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Index>,
             >>::sse_decode(&mut deserializer);
             let api_search_term = <String>::sse_decode(&mut deserializer);
-            let api_book_titles = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_books = <crate::api::search_engine::BookFilter>::sse_decode(&mut deserializer);
             let api_fuzzy = <bool>::sse_decode(&mut deserializer);
+            let api_proximity =
+                <Option<crate::api::search_engine::Proximity>>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::search_engine::MatchOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::api::search_engine::SearchEngineError>(
                     (move || {
                         let mut api_index_guard = None;
                         let decode_indices_ =
@@ -213,8 +217,271 @@ fn wire__crate__api__search_engine__SearchEngine_create_search_query_impl(
                             crate::api::search_engine::SearchEngine::create_search_query(
                                 &*api_index_guard,
                                 &api_search_term,
-                                &api_book_titles,
+                                &api_books,
                                 api_fuzzy,
+                                api_proximity,
+                                api_options,
+                            )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__search_engine__SearchEngine_create_structured_query_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "SearchEngine_create_structured_query",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_index = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Index>,
+            >>::sse_decode(&mut deserializer);
+            let api_query = <crate::api::search_engine::SearchQuery>::sse_decode(&mut deserializer);
+            let api_books = <crate::api::search_engine::BookFilter>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::search_engine::MatchOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::search_engine::SearchEngineError>(
+                    (move || {
+                        let mut api_index_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_index, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_index_guard = Some(api_index.lockable_decode_sync_ref()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_index_guard = api_index_guard.unwrap();
+                        let output_ok =
+                            crate::api::search_engine::SearchEngine::create_structured_query(
+                                &*api_index_guard,
+                                &api_query,
+                                &api_books,
+                                api_options,
+                            )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__search_engine__SearchEngine_explain_query_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "SearchEngine_explain_query",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>,
+            >>::sse_decode(&mut deserializer);
+            let api_search_term = <String>::sse_decode(&mut deserializer);
+            let api_fuzzy = <bool>::sse_decode(&mut deserializer);
+            let api_proximity =
+                <Option<crate::api::search_engine::Proximity>>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::search_engine::MatchOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::search_engine::SearchEngineError>(
+                    (move || {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::search_engine::SearchEngine::explain_query(
+                            &*api_that_guard,
+                            &api_search_term,
+                            api_fuzzy,
+                            api_proximity,
+                            api_options,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__search_engine__SearchEngine_explain_score_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "SearchEngine_explain_score",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>,
+            >>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_books = <crate::api::search_engine::BookFilter>::sse_decode(&mut deserializer);
+            let api_fuzzy = <bool>::sse_decode(&mut deserializer);
+            let api_proximity =
+                <Option<crate::api::search_engine::Proximity>>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::search_engine::MatchOptions>::sse_decode(&mut deserializer);
+            let api_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::search_engine::SearchEngineError>(
+                    (move || {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::search_engine::SearchEngine::explain_score(
+                            &*api_that_guard,
+                            &api_query,
+                            &api_books,
+                            api_fuzzy,
+                            api_proximity,
+                            api_options,
+                            api_id,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__search_engine__SearchEngine_highlight_in_document_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "SearchEngine_highlight_in_document",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>,
+            >>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_title = <String>::sse_decode(&mut deserializer);
+            let api_segment_start = <u64>::sse_decode(&mut deserializer);
+            let api_segment_end = <u64>::sse_decode(&mut deserializer);
+            let api_fuzzy = <bool>::sse_decode(&mut deserializer);
+            let api_proximity =
+                <Option<crate::api::search_engine::Proximity>>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::search_engine::MatchOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::search_engine::SearchEngineError>(
+                    (move || {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::search_engine::SearchEngine::highlight_in_document(
+                                &*api_that_guard,
+                                &api_query,
+                                &api_title,
+                                api_segment_start,
+                                api_segment_end,
+                                api_fuzzy,
+                                api_proximity,
+                                api_options,
                             )?;
                         Ok(output_ok)
                     })(),
@@ -248,12 +515,12 @@ fn wire__crate__api__search_engine__SearchEngine_new_impl(
             let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::search_engine::SearchEngine::new(&api_path),
-                    )?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, crate::api::search_engine::SearchEngineError>(
+                    (move || {
+                        let output_ok = crate::api::search_engine::SearchEngine::new(&api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
@@ -284,12 +551,23 @@ fn wire__crate__api__search_engine__SearchEngine_search_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>,
             >>::sse_decode(&mut deserializer);
             let api_query = <String>::sse_decode(&mut deserializer);
-            let api_books = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_books = <crate::api::search_engine::BookFilter>::sse_decode(&mut deserializer);
             let api_limit = <u32>::sse_decode(&mut deserializer);
             let api_fuzzy = <bool>::sse_decode(&mut deserializer);
+            let api_proximity =
+                <Option<crate::api::search_engine::Proximity>>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::search_engine::MatchOptions>::sse_decode(&mut deserializer);
+            let api_snippet_options =
+                <crate::api::search_engine::SnippetOptions>::sse_decode(&mut deserializer);
+            let api_context_segments = <u32>::sse_decode(&mut deserializer);
+            let api_order = <crate::api::search_engine::SortOrder>::sse_decode(&mut deserializer);
+            let api_offset = <u32>::sse_decode(&mut deserializer);
+            let api_after =
+                <Option<crate::api::search_engine::SearchCursor>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::api::search_engine::SearchEngineError>(
                     (move || {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -311,6 +589,13 @@ fn wire__crate__api__search_engine__SearchEngine_search_impl(
                             &api_books,
                             api_limit,
                             api_fuzzy,
+                            api_proximity,
+                            api_options,
+                            api_snippet_options,
+                            api_context_segments,
+                            api_order,
+                            api_offset,
+                            api_after,
                         )?;
                         Ok(output_ok)
                     })(),
@@ -319,7 +604,7 @@ fn wire__crate__api__search_engine__SearchEngine_search_impl(
         },
     )
 }
-fn wire__crate__api__search_engine__SearchEngine_search_stream_impl(
+fn wire__crate__api__search_engine__SearchEngine_search_gematria_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -327,7 +612,7 @@ fn wire__crate__api__search_engine__SearchEngine_search_stream_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "SearchEngine_search_stream",
+            debug_name: "SearchEngine_search_gematria",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -344,17 +629,16 @@ fn wire__crate__api__search_engine__SearchEngine_search_stream_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>,
             >>::sse_decode(&mut deserializer);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_sink = <StreamSink<
-                Vec<crate::api::search_engine::SearchResult>,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
-            let api_books = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_value = <u32>::sse_decode(&mut deserializer);
+            let api_books = <crate::api::search_engine::BookFilter>::sse_decode(&mut deserializer);
             let api_limit = <u32>::sse_decode(&mut deserializer);
-            let api_fuzzy = <bool>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::search_engine::GematriaOptions>::sse_decode(&mut deserializer);
+            let api_snippet_options =
+                <crate::api::search_engine::SnippetOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                transform_result_sse::<_, crate::api::search_engine::SearchEngineError>(
                     (move || {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -370,13 +654,13 @@ fn wire__crate__api__search_engine__SearchEngine_search_stream_impl(
                             }
                         }
                         let mut api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::search_engine::SearchEngine::search_stream(
+                        let output_ok = crate::api::search_engine::SearchEngine::search_gematria(
                             &mut *api_that_guard,
-                            &api_query,
-                            api_sink,
+                            api_value,
                             &api_books,
                             api_limit,
-                            api_fuzzy,
+                            api_options,
+                            api_snippet_options,
                         )?;
                         Ok(output_ok)
                     })(),
@@ -385,16 +669,17 @@ fn wire__crate__api__search_engine__SearchEngine_search_stream_impl(
         },
     )
 }
-fn wire__crate__api__search_engine__test_bindings_impl(
+fn wire__crate__api__search_engine__SearchEngine_search_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_bindings",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "SearchEngine_search_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -406,438 +691,2662 @@ fn wire__crate__api__search_engine__test_bindings_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>,
+            >>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                Vec<crate::api::search_engine::SearchResult>,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            let api_books = <crate::api::search_engine::BookFilter>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_fuzzy = <bool>::sse_decode(&mut deserializer);
+            let api_proximity =
+                <Option<crate::api::search_engine::Proximity>>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::search_engine::MatchOptions>::sse_decode(&mut deserializer);
+            let api_order = <crate::api::search_engine::SortOrder>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::search_engine::test_bindings(api_name))?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-
-// Section: related_funcs
-
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Box<dyn Query>>
-);
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Index>
+            move |context| {
+                transform_result_sse::<_, crate::api::search_engine::SearchEngineError>(
+                    (move || {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let mut api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::search_engine::SearchEngine::search_stream(
+                            &mut *api_that_guard,
+                            &api_query,
+                            api_sink,
+                            &api_books,
+                            api_limit,
+                            api_fuzzy,
+                            api_proximity,
+                            api_options,
+                            api_order,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__search_engine__SearchEngine_search_structured_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "SearchEngine_search_structured",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>,
+            >>::sse_decode(&mut deserializer);
+            let api_query = <crate::api::search_engine::SearchQuery>::sse_decode(&mut deserializer);
+            let api_books = <crate::api::search_engine::BookFilter>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::search_engine::MatchOptions>::sse_decode(&mut deserializer);
+            let api_snippet_options =
+                <crate::api::search_engine::SnippetOptions>::sse_decode(&mut deserializer);
+            let api_order = <crate::api::search_engine::SortOrder>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::search_engine::SearchEngineError>(
+                    (move || {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, true,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                                _ => unreachable!(),
+                            }
+                        }
+                        let mut api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::search_engine::SearchEngine::search_structured(
+                            &mut *api_that_guard,
+                            &api_query,
+                            &api_books,
+                            api_limit,
+                            api_options,
+                            api_snippet_options,
+                            api_order,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__search_engine__book_filter_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "book_filter_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::search_engine::BookFilter::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__search_engine__load_abbreviations_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_abbreviations",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::search_engine::SearchEngineError>(
+                    (move || {
+                        let output_ok = crate::api::search_engine::load_abbreviations(&api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__search_engine__load_root_lexicon_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_root_lexicon",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::search_engine::SearchEngineError>(
+                    (move || {
+                        let output_ok = crate::api::search_engine::load_root_lexicon(&api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__search_engine__match_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "match_options_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::search_engine::MatchOptions::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__search_engine__parse_hebrew_numeral_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_hebrew_numeral",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::search_engine::parse_hebrew_numeral(&api_text),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search_engine__snippet_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "snippet_options_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::search_engine::SnippetOptions::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__search_engine__test_bindings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_bindings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::search_engine::test_bindings(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+
+// Section: related_funcs
+
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Box<dyn Query>>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Index>
 );
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>
 );
 
-// Section: dart2rust
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for Box<dyn Query> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Box<dyn Query>>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for SearchEngine {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Box<dyn Query>>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Index>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        Vec<crate::api::search_engine::SearchResult>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::search_engine::BookCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        return crate::api::search_engine::BookCount {
+            title: var_title,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::BookFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_allBooks = <bool>::sse_decode(deserializer);
+        let mut var_includeTitles = <Vec<String>>::sse_decode(deserializer);
+        let mut var_includeCategories = <Vec<String>>::sse_decode(deserializer);
+        let mut var_excludeTitles = <Vec<String>>::sse_decode(deserializer);
+        let mut var_excludeCategories = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::search_engine::BookFilter {
+            all_books: var_allBooks,
+            include_titles: var_includeTitles,
+            include_categories: var_includeCategories,
+            exclude_titles: var_excludeTitles,
+            exclude_categories: var_excludeCategories,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::search_engine::CategoryCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_category = <String>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        return crate::api::search_engine::CategoryCount {
+            category: var_category,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::ClauseOccur {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::search_engine::ClauseOccur::Should,
+            1 => crate::api::search_engine::ClauseOccur::Must,
+            2 => crate::api::search_engine::ClauseOccur::MustNot,
+            _ => unreachable!("Invalid variant for ClauseOccur: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::search_engine::FieldFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <crate::api::search_engine::FilterField>::sse_decode(deserializer);
+        let mut var_values = <Vec<String>>::sse_decode(deserializer);
+        let mut var_exclude = <bool>::sse_decode(deserializer);
+        return crate::api::search_engine::FieldFilter {
+            field: var_field,
+            values: var_values,
+            exclude: var_exclude,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::FilterField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::search_engine::FilterField::Title,
+            1 => crate::api::search_engine::FilterField::FilePath,
+            _ => unreachable!("Invalid variant for FilterField: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::GematriaMethod {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::search_engine::GematriaMethod::Standard,
+            1 => crate::api::search_engine::GematriaMethod::Small,
+            2 => crate::api::search_engine::GematriaMethod::Large,
+            _ => unreachable!("Invalid variant for GematriaMethod: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::GematriaOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_method = <crate::api::search_engine::GematriaMethod>::sse_decode(deserializer);
+        let mut var_minWords = <u32>::sse_decode(deserializer);
+        let mut var_maxWords = <u32>::sse_decode(deserializer);
+        return crate::api::search_engine::GematriaOptions {
+            method: var_method,
+            min_words: var_minWords,
+            max_words: var_maxWords,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::Highlight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_byteStart = <u32>::sse_decode(deserializer);
+        let mut var_byteEnd = <u32>::sse_decode(deserializer);
+        let mut var_charStart = <u32>::sse_decode(deserializer);
+        let mut var_charEnd = <u32>::sse_decode(deserializer);
+        let mut var_term = <String>::sse_decode(deserializer);
+        return crate::api::search_engine::Highlight {
+            byte_start: var_byteStart,
+            byte_end: var_byteEnd,
+            char_start: var_charStart,
+            char_end: var_charEnd,
+            term: var_term,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::HighlightTags {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_open = <String>::sse_decode(deserializer);
+        let mut var_close = <String>::sse_decode(deserializer);
+        return crate::api::search_engine::HighlightTags {
+            open: var_open,
+            close: var_close,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search_engine::BookCount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search_engine::BookCount>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search_engine::CategoryCount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search_engine::CategoryCount>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search_engine::FieldFilter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search_engine::FieldFilter>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search_engine::Highlight> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search_engine::Highlight>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search_engine::NeighborSegment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search_engine::NeighborSegment>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search_engine::QueryClause> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search_engine::QueryClause>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search_engine::QueryExpansion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search_engine::QueryExpansion>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search_engine::QueryNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search_engine::QueryNode>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search_engine::ScoreExplanation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search_engine::ScoreExplanation>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search_engine::SearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search_engine::SearchResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search_engine::SegmentHighlights> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search_engine::SegmentHighlights>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::search_engine::MatchOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_prefixInsensitive = <bool>::sse_decode(deserializer);
+        let mut var_spellingInsensitive = <bool>::sse_decode(deserializer);
+        let mut var_byRoot = <bool>::sse_decode(deserializer);
+        let mut var_divineNames = <bool>::sse_decode(deserializer);
+        return crate::api::search_engine::MatchOptions {
+            prefix_insensitive: var_prefixInsensitive,
+            spelling_insensitive: var_spellingInsensitive,
+            by_root: var_byRoot,
+            divine_names: var_divineNames,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::NeighborSegment {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_segment = <u64>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        return crate::api::search_engine::NeighborSegment {
+            segment: var_segment,
+            text: var_text,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::search_engine::HighlightTags> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::search_engine::HighlightTags>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::search_engine::Proximity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::search_engine::Proximity>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::search_engine::QueryNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::search_engine::QueryNode>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::search_engine::ScoreExplanation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::search_engine::ScoreExplanation>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::search_engine::SearchCursor> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::search_engine::SearchCursor>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::search_engine::SearchEngineError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::search_engine::SearchEngineError>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::search_engine::Proximity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxDistance = <u32>::sse_decode(deserializer);
+        let mut var_ordered = <bool>::sse_decode(deserializer);
+        return crate::api::search_engine::Proximity {
+            max_distance: var_maxDistance,
+            ordered: var_ordered,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::QueryClause {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_words = <Vec<String>>::sse_decode(deserializer);
+        let mut var_occur = <crate::api::search_engine::ClauseOccur>::sse_decode(deserializer);
+        let mut var_fuzziness = <u8>::sse_decode(deserializer);
+        let mut var_proximity =
+            <Option<crate::api::search_engine::Proximity>>::sse_decode(deserializer);
+        return crate::api::search_engine::QueryClause {
+            words: var_words,
+            occur: var_occur,
+            fuzziness: var_fuzziness,
+            proximity: var_proximity,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::QueryExpansion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_original = <String>::sse_decode(deserializer);
+        let mut var_alternatives = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::search_engine::QueryExpansion {
+            original: var_original,
+            alternatives: var_alternatives,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::QueryExplanation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_parsedQuery = <String>::sse_decode(deserializer);
+        let mut var_expansions =
+            <Vec<crate::api::search_engine::QueryExpansion>>::sse_decode(deserializer);
+        let mut var_root = <Option<crate::api::search_engine::QueryNode>>::sse_decode(deserializer);
+        let mut var_error =
+            <Option<crate::api::search_engine::SearchEngineError>>::sse_decode(deserializer);
+        return crate::api::search_engine::QueryExplanation {
+            parsed_query: var_parsedQuery,
+            expansions: var_expansions,
+            root: var_root,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::QueryNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::search_engine::QueryNodeKind>::sse_decode(deserializer);
+        let mut var_occur = <crate::api::search_engine::ClauseOccur>::sse_decode(deserializer);
+        let mut var_field = <String>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_terms = <Vec<String>>::sse_decode(deserializer);
+        let mut var_distance = <u32>::sse_decode(deserializer);
+        let mut var_ordered = <bool>::sse_decode(deserializer);
+        let mut var_boost = <f32>::sse_decode(deserializer);
+        let mut var_fuzzyExpansions = <Vec<String>>::sse_decode(deserializer);
+        let mut var_children =
+            <Vec<crate::api::search_engine::QueryNode>>::sse_decode(deserializer);
+        return crate::api::search_engine::QueryNode {
+            kind: var_kind,
+            occur: var_occur,
+            field: var_field,
+            text: var_text,
+            terms: var_terms,
+            distance: var_distance,
+            ordered: var_ordered,
+            boost: var_boost,
+            fuzzy_expansions: var_fuzzyExpansions,
+            children: var_children,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::QueryNodeKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::search_engine::QueryNodeKind::Group,
+            1 => crate::api::search_engine::QueryNodeKind::Term,
+            2 => crate::api::search_engine::QueryNodeKind::Fuzzy,
+            3 => crate::api::search_engine::QueryNodeKind::Phrase,
+            4 => crate::api::search_engine::QueryNodeKind::Proximity,
+            5 => crate::api::search_engine::QueryNodeKind::Range,
+            6 => crate::api::search_engine::QueryNodeKind::TermSet,
+            7 => crate::api::search_engine::QueryNodeKind::Exists,
+            8 => crate::api::search_engine::QueryNodeKind::All,
+            9 => crate::api::search_engine::QueryNodeKind::Nothing,
+            _ => unreachable!("Invalid variant for QueryNodeKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::ScoreExplanation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_value = <f32>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_details =
+            <Vec<crate::api::search_engine::ScoreExplanation>>::sse_decode(deserializer);
+        let mut var_context = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::search_engine::ScoreExplanation {
+            value: var_value,
+            description: var_description,
+            details: var_details,
+            context: var_context,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::SearchCursor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_score = <f32>::sse_decode(deserializer);
+        let mut var_id = <u64>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_segment = <u64>::sse_decode(deserializer);
+        let mut var_segmentOrd = <u32>::sse_decode(deserializer);
+        let mut var_docId = <u32>::sse_decode(deserializer);
+        return crate::api::search_engine::SearchCursor {
+            score: var_score,
+            id: var_id,
+            title: var_title,
+            segment: var_segment,
+            segment_ord: var_segmentOrd,
+            doc_id: var_docId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::SearchEngineError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <crate::api::search_engine::SearchEngineErrorKind>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_position = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::search_engine::SearchEngineError {
+            kind: var_kind,
+            message: var_message,
+            position: var_position,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::SearchEngineErrorKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::search_engine::SearchEngineErrorKind::IndexLocked,
+            1 => crate::api::search_engine::SearchEngineErrorKind::CorruptIndex,
+            2 => crate::api::search_engine::SearchEngineErrorKind::InvalidQuery,
+            3 => crate::api::search_engine::SearchEngineErrorKind::SchemaMismatch,
+            4 => crate::api::search_engine::SearchEngineErrorKind::Io,
+            5 => crate::api::search_engine::SearchEngineErrorKind::Other,
+            _ => unreachable!("Invalid variant for SearchEngineErrorKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::SearchQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_clauses =
+            <Vec<crate::api::search_engine::QueryClause>>::sse_decode(deserializer);
+        let mut var_filters =
+            <Vec<crate::api::search_engine::FieldFilter>>::sse_decode(deserializer);
+        return crate::api::search_engine::SearchQuery {
+            clauses: var_clauses,
+            filters: var_filters,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::SearchResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_hits = <Vec<crate::api::search_engine::SearchResult>>::sse_decode(deserializer);
+        let mut var_totalCount = <u64>::sse_decode(deserializer);
+        let mut var_perBookCounts =
+            <Vec<crate::api::search_engine::BookCount>>::sse_decode(deserializer);
+        let mut var_perCategoryCounts =
+            <Vec<crate::api::search_engine::CategoryCount>>::sse_decode(deserializer);
+        return crate::api::search_engine::SearchResponse {
+            hits: var_hits,
+            total_count: var_totalCount,
+            per_book_counts: var_perBookCounts,
+            per_category_counts: var_perCategoryCounts,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::SearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_highlights =
+            <Vec<crate::api::search_engine::Highlight>>::sse_decode(deserializer);
+        let mut var_html = <Option<String>>::sse_decode(deserializer);
+        let mut var_id = <u64>::sse_decode(deserializer);
+        let mut var_segment = <u64>::sse_decode(deserializer);
+        let mut var_isPdf = <bool>::sse_decode(deserializer);
+        let mut var_filePath = <String>::sse_decode(deserializer);
+        let mut var_cursor = <crate::api::search_engine::SearchCursor>::sse_decode(deserializer);
+        let mut var_preceding =
+            <Vec<crate::api::search_engine::NeighborSegment>>::sse_decode(deserializer);
+        let mut var_following =
+            <Vec<crate::api::search_engine::NeighborSegment>>::sse_decode(deserializer);
+        return crate::api::search_engine::SearchResult {
+            title: var_title,
+            text: var_text,
+            highlights: var_highlights,
+            html: var_html,
+            id: var_id,
+            segment: var_segment,
+            is_pdf: var_isPdf,
+            file_path: var_filePath,
+            cursor: var_cursor,
+            preceding: var_preceding,
+            following: var_following,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::SegmentHighlights {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_segment = <u64>::sse_decode(deserializer);
+        let mut var_highlights =
+            <Vec<crate::api::search_engine::Highlight>>::sse_decode(deserializer);
+        return crate::api::search_engine::SegmentHighlights {
+            segment: var_segment,
+            highlights: var_highlights,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::SnippetBoundary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::search_engine::SnippetBoundary::None,
+            1 => crate::api::search_engine::SnippetBoundary::Word,
+            2 => crate::api::search_engine::SnippetBoundary::Sentence,
+            _ => unreachable!("Invalid variant for SnippetBoundary: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::SnippetOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxChars = <u32>::sse_decode(deserializer);
+        let mut var_fragments = <u32>::sse_decode(deserializer);
+        let mut var_separator = <String>::sse_decode(deserializer);
+        let mut var_boundary =
+            <crate::api::search_engine::SnippetBoundary>::sse_decode(deserializer);
+        let mut var_htmlTags =
+            <Option<crate::api::search_engine::HighlightTags>>::sse_decode(deserializer);
+        return crate::api::search_engine::SnippetOptions {
+            max_chars: var_maxChars,
+            fragments: var_fragments,
+            separator: var_separator,
+            boundary: var_boundary,
+            html_tags: var_htmlTags,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search_engine::SortOrder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::search_engine::SortOrder::Relevance,
+            1 => crate::api::search_engine::SortOrder::LibraryOrder,
+            2 => crate::api::search_engine::SortOrder::LibraryOrderDescending,
+            3 => crate::api::search_engine::SortOrder::Title,
+            4 => crate::api::search_engine::SortOrder::TitleDescending,
+            _ => unreachable!("Invalid variant for SortOrder: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__search_engine__SearchEngine_add_document_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        2 => wire__crate__api__search_engine__SearchEngine_commit_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => wire__crate__api__search_engine__SearchEngine_create_search_query_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => wire__crate__api__search_engine__SearchEngine_create_structured_query_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__search_engine__SearchEngine_explain_query_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__search_engine__SearchEngine_explain_score_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => wire__crate__api__search_engine__SearchEngine_highlight_in_document_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__search_engine__SearchEngine_new_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__search_engine__SearchEngine_search_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__search_engine__SearchEngine_search_gematria_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        11 => wire__crate__api__search_engine__SearchEngine_search_stream_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__search_engine__SearchEngine_search_structured_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__search_engine__book_filter_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__search_engine__load_abbreviations_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        15 => wire__crate__api__search_engine__load_root_lexicon_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        16 => wire__crate__api__search_engine__match_options_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => wire__crate__api__search_engine__snippet_options_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        17 => {
+            wire__crate__api__search_engine__parse_hebrew_numeral_impl(ptr, rust_vec_len, data_len)
+        }
+        19 => wire__crate__api__search_engine__test_bindings_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<Box<dyn Query>> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Box<dyn Query>> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Box<dyn Query>>> for Box<dyn Query> {
+    fn into_into_dart(self) -> FrbWrapper<Box<dyn Query>> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<SearchEngine> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SearchEngine> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SearchEngine>> for SearchEngine {
+    fn into_into_dart(self) -> FrbWrapper<SearchEngine> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::BookCount {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::BookCount
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::BookCount>
+    for crate::api::search_engine::BookCount
+{
+    fn into_into_dart(self) -> crate::api::search_engine::BookCount {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::BookFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.all_books.into_into_dart().into_dart(),
+            self.include_titles.into_into_dart().into_dart(),
+            self.include_categories.into_into_dart().into_dart(),
+            self.exclude_titles.into_into_dart().into_dart(),
+            self.exclude_categories.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::BookFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::BookFilter>
+    for crate::api::search_engine::BookFilter
+{
+    fn into_into_dart(self) -> crate::api::search_engine::BookFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::CategoryCount {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.category.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::CategoryCount
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::CategoryCount>
+    for crate::api::search_engine::CategoryCount
+{
+    fn into_into_dart(self) -> crate::api::search_engine::CategoryCount {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::ClauseOccur {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Should => 0.into_dart(),
+            Self::Must => 1.into_dart(),
+            Self::MustNot => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::ClauseOccur
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::ClauseOccur>
+    for crate::api::search_engine::ClauseOccur
+{
+    fn into_into_dart(self) -> crate::api::search_engine::ClauseOccur {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::FieldFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.values.into_into_dart().into_dart(),
+            self.exclude.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::FieldFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::FieldFilter>
+    for crate::api::search_engine::FieldFilter
+{
+    fn into_into_dart(self) -> crate::api::search_engine::FieldFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::FilterField {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Title => 0.into_dart(),
+            Self::FilePath => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::FilterField
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::FilterField>
+    for crate::api::search_engine::FilterField
+{
+    fn into_into_dart(self) -> crate::api::search_engine::FilterField {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::GematriaMethod {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Standard => 0.into_dart(),
+            Self::Small => 1.into_dart(),
+            Self::Large => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::GematriaMethod
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::GematriaMethod>
+    for crate::api::search_engine::GematriaMethod
+{
+    fn into_into_dart(self) -> crate::api::search_engine::GematriaMethod {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::GematriaOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.method.into_into_dart().into_dart(),
+            self.min_words.into_into_dart().into_dart(),
+            self.max_words.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::GematriaOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::GematriaOptions>
+    for crate::api::search_engine::GematriaOptions
+{
+    fn into_into_dart(self) -> crate::api::search_engine::GematriaOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::Highlight {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.byte_start.into_into_dart().into_dart(),
+            self.byte_end.into_into_dart().into_dart(),
+            self.char_start.into_into_dart().into_dart(),
+            self.char_end.into_into_dart().into_dart(),
+            self.term.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::Highlight
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::Highlight>
+    for crate::api::search_engine::Highlight
+{
+    fn into_into_dart(self) -> crate::api::search_engine::Highlight {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::HighlightTags {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.open.into_into_dart().into_dart(),
+            self.close.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::HighlightTags
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::HighlightTags>
+    for crate::api::search_engine::HighlightTags
+{
+    fn into_into_dart(self) -> crate::api::search_engine::HighlightTags {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::MatchOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.prefix_insensitive.into_into_dart().into_dart(),
+            self.spelling_insensitive.into_into_dart().into_dart(),
+            self.by_root.into_into_dart().into_dart(),
+            self.divine_names.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::MatchOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::MatchOptions>
+    for crate::api::search_engine::MatchOptions
+{
+    fn into_into_dart(self) -> crate::api::search_engine::MatchOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::NeighborSegment {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.segment.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::NeighborSegment
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::NeighborSegment>
+    for crate::api::search_engine::NeighborSegment
+{
+    fn into_into_dart(self) -> crate::api::search_engine::NeighborSegment {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::Proximity {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_distance.into_into_dart().into_dart(),
+            self.ordered.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::Proximity
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::Proximity>
+    for crate::api::search_engine::Proximity
+{
+    fn into_into_dart(self) -> crate::api::search_engine::Proximity {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::QueryClause {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.words.into_into_dart().into_dart(),
+            self.occur.into_into_dart().into_dart(),
+            self.fuzziness.into_into_dart().into_dart(),
+            self.proximity.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::QueryClause
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::QueryClause>
+    for crate::api::search_engine::QueryClause
+{
+    fn into_into_dart(self) -> crate::api::search_engine::QueryClause {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::QueryExpansion {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.original.into_into_dart().into_dart(),
+            self.alternatives.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::QueryExpansion
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::QueryExpansion>
+    for crate::api::search_engine::QueryExpansion
+{
+    fn into_into_dart(self) -> crate::api::search_engine::QueryExpansion {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::QueryExplanation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.parsed_query.into_into_dart().into_dart(),
+            self.expansions.into_into_dart().into_dart(),
+            self.root.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::QueryExplanation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::QueryExplanation>
+    for crate::api::search_engine::QueryExplanation
+{
+    fn into_into_dart(self) -> crate::api::search_engine::QueryExplanation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::QueryNode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.occur.into_into_dart().into_dart(),
+            self.field.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.terms.into_into_dart().into_dart(),
+            self.distance.into_into_dart().into_dart(),
+            self.ordered.into_into_dart().into_dart(),
+            self.boost.into_into_dart().into_dart(),
+            self.fuzzy_expansions.into_into_dart().into_dart(),
+            self.children.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::QueryNode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::QueryNode>
+    for crate::api::search_engine::QueryNode
+{
+    fn into_into_dart(self) -> crate::api::search_engine::QueryNode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::QueryNodeKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Group => 0.into_dart(),
+            Self::Term => 1.into_dart(),
+            Self::Fuzzy => 2.into_dart(),
+            Self::Phrase => 3.into_dart(),
+            Self::Proximity => 4.into_dart(),
+            Self::Range => 5.into_dart(),
+            Self::TermSet => 6.into_dart(),
+            Self::Exists => 7.into_dart(),
+            Self::All => 8.into_dart(),
+            Self::Nothing => 9.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::QueryNodeKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::QueryNodeKind>
+    for crate::api::search_engine::QueryNodeKind
+{
+    fn into_into_dart(self) -> crate::api::search_engine::QueryNodeKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::ScoreExplanation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.value.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.details.into_into_dart().into_dart(),
+            self.context.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::ScoreExplanation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::ScoreExplanation>
+    for crate::api::search_engine::ScoreExplanation
+{
+    fn into_into_dart(self) -> crate::api::search_engine::ScoreExplanation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::SearchCursor {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.score.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.segment.into_into_dart().into_dart(),
+            self.segment_ord.into_into_dart().into_dart(),
+            self.doc_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::SearchCursor
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::SearchCursor>
+    for crate::api::search_engine::SearchCursor
+{
+    fn into_into_dart(self) -> crate::api::search_engine::SearchCursor {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::SearchEngineError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.position.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::SearchEngineError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::SearchEngineError>
+    for crate::api::search_engine::SearchEngineError
+{
+    fn into_into_dart(self) -> crate::api::search_engine::SearchEngineError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::SearchEngineErrorKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::IndexLocked => 0.into_dart(),
+            Self::CorruptIndex => 1.into_dart(),
+            Self::InvalidQuery => 2.into_dart(),
+            Self::SchemaMismatch => 3.into_dart(),
+            Self::Io => 4.into_dart(),
+            Self::Other => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::SearchEngineErrorKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::SearchEngineErrorKind>
+    for crate::api::search_engine::SearchEngineErrorKind
+{
+    fn into_into_dart(self) -> crate::api::search_engine::SearchEngineErrorKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::SearchQuery {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.clauses.into_into_dart().into_dart(),
+            self.filters.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::SearchQuery
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::SearchQuery>
+    for crate::api::search_engine::SearchQuery
+{
+    fn into_into_dart(self) -> crate::api::search_engine::SearchQuery {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::SearchResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.hits.into_into_dart().into_dart(),
+            self.total_count.into_into_dart().into_dart(),
+            self.per_book_counts.into_into_dart().into_dart(),
+            self.per_category_counts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::SearchResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::SearchResponse>
+    for crate::api::search_engine::SearchResponse
+{
+    fn into_into_dart(self) -> crate::api::search_engine::SearchResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::SearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.highlights.into_into_dart().into_dart(),
+            self.html.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.segment.into_into_dart().into_dart(),
+            self.is_pdf.into_into_dart().into_dart(),
+            self.file_path.into_into_dart().into_dart(),
+            self.cursor.into_into_dart().into_dart(),
+            self.preceding.into_into_dart().into_dart(),
+            self.following.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::SearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::SearchResult>
+    for crate::api::search_engine::SearchResult
+{
+    fn into_into_dart(self) -> crate::api::search_engine::SearchResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::SegmentHighlights {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.segment.into_into_dart().into_dart(),
+            self.highlights.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::SegmentHighlights
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::SegmentHighlights>
+    for crate::api::search_engine::SegmentHighlights
+{
+    fn into_into_dart(self) -> crate::api::search_engine::SegmentHighlights {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::SnippetBoundary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::None => 0.into_dart(),
+            Self::Word => 1.into_dart(),
+            Self::Sentence => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::SnippetBoundary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::SnippetBoundary>
+    for crate::api::search_engine::SnippetBoundary
+{
+    fn into_into_dart(self) -> crate::api::search_engine::SnippetBoundary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::SnippetOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_chars.into_into_dart().into_dart(),
+            self.fragments.into_into_dart().into_dart(),
+            self.separator.into_into_dart().into_dart(),
+            self.boundary.into_into_dart().into_dart(),
+            self.html_tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::SnippetOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::SnippetOptions>
+    for crate::api::search_engine::SnippetOptions
+{
+    fn into_into_dart(self) -> crate::api::search_engine::SnippetOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search_engine::SortOrder {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Relevance => 0.into_dart(),
+            Self::LibraryOrder => 1.into_dart(),
+            Self::LibraryOrderDescending => 2.into_dart(),
+            Self::Title => 3.into_dart(),
+            Self::TitleDescending => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search_engine::SortOrder
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::SortOrder>
+    for crate::api::search_engine::SortOrder
+{
+    fn into_into_dart(self) -> crate::api::search_engine::SortOrder {
+        self
+    }
+}
 
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
     }
 }
 
-impl SseDecode for Box<dyn Query> {
+impl SseEncode for Box<dyn Query> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Box<dyn Query>>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Box < dyn Query >>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode for SearchEngine {
+impl SseEncode for SearchEngine {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
+impl SseEncode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Box<dyn Query>>>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Index>> {
+impl SseEncode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Index>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode
+impl SseEncode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode
+impl SseEncode
     for StreamSink<
         Vec<crate::api::search_engine::SearchResult>,
         flutter_rust_bridge::for_generated::SseCodec,
     >
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for String {
+impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for crate::api::search_engine::BookCount {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.title, serializer);
+        <u64>::sse_encode(self.count, serializer);
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseEncode for crate::api::search_engine::BookFilter {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.all_books, serializer);
+        <Vec<String>>::sse_encode(self.include_titles, serializer);
+        <Vec<String>>::sse_encode(self.include_categories, serializer);
+        <Vec<String>>::sse_encode(self.exclude_titles, serializer);
+        <Vec<String>>::sse_encode(self.exclude_categories, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::search_engine::CategoryCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.category, serializer);
+        <u64>::sse_encode(self.count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::search_engine::ClauseOccur {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::search_engine::ClauseOccur::Should => 0,
+                crate::api::search_engine::ClauseOccur::Must => 1,
+                crate::api::search_engine::ClauseOccur::MustNot => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::search_engine::FieldFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::search_engine::FilterField>::sse_encode(self.field, serializer);
+        <Vec<String>>::sse_encode(self.values, serializer);
+        <bool>::sse_encode(self.exclude, serializer);
+    }
+}
+
+impl SseEncode for crate::api::search_engine::FilterField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::search_engine::FilterField::Title => 0,
+                crate::api::search_engine::FilterField::FilePath => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::search_engine::GematriaMethod {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::search_engine::GematriaMethod::Standard => 0,
+                crate::api::search_engine::GematriaMethod::Small => 1,
+                crate::api::search_engine::GematriaMethod::Large => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::search_engine::GematriaOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::search_engine::GematriaMethod>::sse_encode(self.method, serializer);
+        <u32>::sse_encode(self.min_words, serializer);
+        <u32>::sse_encode(self.max_words, serializer);
+    }
+}
+
+impl SseEncode for crate::api::search_engine::Highlight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.byte_start, serializer);
+        <u32>::sse_encode(self.byte_end, serializer);
+        <u32>::sse_encode(self.char_start, serializer);
+        <u32>::sse_encode(self.char_end, serializer);
+        <String>::sse_encode(self.term, serializer);
+    }
+}
+
+impl SseEncode for crate::api::search_engine::HighlightTags {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.open, serializer);
+        <String>::sse_encode(self.close, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<crate::api::search_engine::BookCount> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search_engine::BookCount>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::search_engine::SearchResult> {
+impl SseEncode for Vec<crate::api::search_engine::CategoryCount> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::search_engine::SearchResult>::sse_decode(
-                deserializer,
-            ));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search_engine::CategoryCount>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::search_engine::FieldFilter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search_engine::FieldFilter>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::search_engine::Highlight> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search_engine::Highlight>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::search_engine::NeighborSegment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search_engine::NeighborSegment>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::search_engine::QueryClause> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search_engine::QueryClause>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::search_engine::QueryExpansion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search_engine::QueryExpansion>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for crate::api::search_engine::SearchResult {
+impl SseEncode for Vec<crate::api::search_engine::QueryNode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_title = <String>::sse_decode(deserializer);
-        let mut var_text = <String>::sse_decode(deserializer);
-        let mut var_id = <u64>::sse_decode(deserializer);
-        let mut var_segment = <u64>::sse_decode(deserializer);
-        let mut var_isPdf = <bool>::sse_decode(deserializer);
-        let mut var_filePath = <String>::sse_decode(deserializer);
-        return crate::api::search_engine::SearchResult {
-            title: var_title,
-            text: var_text,
-            id: var_id,
-            segment: var_segment,
-            is_pdf: var_isPdf,
-            file_path: var_filePath,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search_engine::QueryNode>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for Vec<crate::api::search_engine::ScoreExplanation> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search_engine::ScoreExplanation>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for Vec<crate::api::search_engine::SearchResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search_engine::SearchResult>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Vec<crate::api::search_engine::SegmentHighlights> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search_engine::SegmentHighlights>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for crate::api::search_engine::MatchOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.prefix_insensitive, serializer);
+        <bool>::sse_encode(self.spelling_insensitive, serializer);
+        <bool>::sse_encode(self.by_root, serializer);
+        <bool>::sse_encode(self.divine_names, serializer);
+    }
 }
 
-impl SseDecode for usize {
+impl SseEncode for crate::api::search_engine::NeighborSegment {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.segment, serializer);
+        <String>::sse_encode(self.text, serializer);
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__search_engine__SearchEngine_add_document_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        2 => wire__crate__api__search_engine__SearchEngine_commit_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        3 => wire__crate__api__search_engine__SearchEngine_create_search_query_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        4 => wire__crate__api__search_engine__SearchEngine_new_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        5 => wire__crate__api__search_engine__SearchEngine_search_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        6 => wire__crate__api__search_engine__SearchEngine_search_stream_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
+impl SseEncode for Option<crate::api::search_engine::HighlightTags> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::search_engine::HighlightTags>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        7 => wire__crate__api__search_engine__test_bindings_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Option<crate::api::search_engine::Proximity> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::search_engine::Proximity>::sse_encode(value, serializer);
+        }
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<Box<dyn Query>> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Option<crate::api::search_engine::QueryNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::search_engine::QueryNode>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<Box<dyn Query>> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<Box<dyn Query>>> for Box<dyn Query> {
-    fn into_into_dart(self) -> FrbWrapper<Box<dyn Query>> {
-        self.into()
+impl SseEncode for Option<crate::api::search_engine::ScoreExplanation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::search_engine::ScoreExplanation>::sse_encode(value, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<SearchEngine> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Option<crate::api::search_engine::SearchCursor> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::search_engine::SearchCursor>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SearchEngine> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SearchEngine>> for SearchEngine {
-    fn into_into_dart(self) -> FrbWrapper<SearchEngine> {
-        self.into()
+impl SseEncode for Option<crate::api::search_engine::SearchEngineError> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::search_engine::SearchEngineError>::sse_encode(value, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::search_engine::SearchResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.title.into_into_dart().into_dart(),
-            self.text.into_into_dart().into_dart(),
-            self.id.into_into_dart().into_dart(),
-            self.segment.into_into_dart().into_dart(),
-            self.is_pdf.into_into_dart().into_dart(),
-            self.file_path.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::search_engine::SearchResult
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::search_engine::SearchResult>
-    for crate::api::search_engine::SearchResult
-{
-    fn into_into_dart(self) -> crate::api::search_engine::SearchResult {
-        self
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for crate::api::search_engine::Proximity {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <u32>::sse_encode(self.max_distance, serializer);
+        <bool>::sse_encode(self.ordered, serializer);
     }
 }
 
-impl SseEncode for Box<dyn Query> {
+impl SseEncode for crate::api::search_engine::QueryClause {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Box < dyn Query >>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <Vec<String>>::sse_encode(self.words, serializer);
+        <crate::api::search_engine::ClauseOccur>::sse_encode(self.occur, serializer);
+        <u8>::sse_encode(self.fuzziness, serializer);
+        <Option<crate::api::search_engine::Proximity>>::sse_encode(self.proximity, serializer);
     }
 }
 
-impl SseEncode for SearchEngine {
+impl SseEncode for crate::api::search_engine::QueryExpansion {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <String>::sse_encode(self.original, serializer);
+        <Vec<String>>::sse_encode(self.alternatives, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Box<dyn Query>>>
-{
+impl SseEncode for crate::api::search_engine::QueryExplanation {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <String>::sse_encode(self.parsed_query, serializer);
+        <Vec<crate::api::search_engine::QueryExpansion>>::sse_encode(self.expansions, serializer);
+        <Option<crate::api::search_engine::QueryNode>>::sse_encode(self.root, serializer);
+        <Option<crate::api::search_engine::SearchEngineError>>::sse_encode(self.error, serializer);
     }
 }
 
-impl SseEncode for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Index>> {
+impl SseEncode for crate::api::search_engine::QueryNode {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <crate::api::search_engine::QueryNodeKind>::sse_encode(self.kind, serializer);
+        <crate::api::search_engine::ClauseOccur>::sse_encode(self.occur, serializer);
+        <String>::sse_encode(self.field, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <Vec<String>>::sse_encode(self.terms, serializer);
+        <u32>::sse_encode(self.distance, serializer);
+        <bool>::sse_encode(self.ordered, serializer);
+        <f32>::sse_encode(self.boost, serializer);
+        <Vec<String>>::sse_encode(self.fuzzy_expansions, serializer);
+        <Vec<crate::api::search_engine::QueryNode>>::sse_encode(self.children, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchEngine>>
-{
+impl SseEncode for crate::api::search_engine::QueryNodeKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::search_engine::QueryNodeKind::Group => 0,
+                crate::api::search_engine::QueryNodeKind::Term => 1,
+                crate::api::search_engine::QueryNodeKind::Fuzzy => 2,
+                crate::api::search_engine::QueryNodeKind::Phrase => 3,
+                crate::api::search_engine::QueryNodeKind::Proximity => 4,
+                crate::api::search_engine::QueryNodeKind::Range => 5,
+                crate::api::search_engine::QueryNodeKind::TermSet => 6,
+                crate::api::search_engine::QueryNodeKind::Exists => 7,
+                crate::api::search_engine::QueryNodeKind::All => 8,
+                crate::api::search_engine::QueryNodeKind::Nothing => 9,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode
-    for StreamSink<
-        Vec<crate::api::search_engine::SearchResult>,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
+impl SseEncode for crate::api::search_engine::ScoreExplanation {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <f32>::sse_encode(self.value, serializer);
+        <String>::sse_encode(self.description, serializer);
+        <Vec<crate::api::search_engine::ScoreExplanation>>::sse_encode(self.details, serializer);
+        <Vec<String>>::sse_encode(self.context, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::search_engine::SearchCursor {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <f32>::sse_encode(self.score, serializer);
+        <u64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <u64>::sse_encode(self.segment, serializer);
+        <u32>::sse_encode(self.segment_ord, serializer);
+        <u32>::sse_encode(self.doc_id, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::search_engine::SearchEngineError {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <crate::api::search_engine::SearchEngineErrorKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<u32>>::sse_encode(self.position, serializer);
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for crate::api::search_engine::SearchEngineErrorKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::search_engine::SearchEngineErrorKind::IndexLocked => 0,
+                crate::api::search_engine::SearchEngineErrorKind::CorruptIndex => 1,
+                crate::api::search_engine::SearchEngineErrorKind::InvalidQuery => 2,
+                crate::api::search_engine::SearchEngineErrorKind::SchemaMismatch => 3,
+                crate::api::search_engine::SearchEngineErrorKind::Io => 4,
+                crate::api::search_engine::SearchEngineErrorKind::Other => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::search_engine::SearchQuery {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <Vec<crate::api::search_engine::QueryClause>>::sse_encode(self.clauses, serializer);
+        <Vec<crate::api::search_engine::FieldFilter>>::sse_encode(self.filters, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::search_engine::SearchResult> {
+impl SseEncode for crate::api::search_engine::SearchResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::search_engine::SearchResult>::sse_encode(item, serializer);
-        }
+        <Vec<crate::api::search_engine::SearchResult>>::sse_encode(self.hits, serializer);
+        <u64>::sse_encode(self.total_count, serializer);
+        <Vec<crate::api::search_engine::BookCount>>::sse_encode(self.per_book_counts, serializer);
+        <Vec<crate::api::search_engine::CategoryCount>>::sse_encode(
+            self.per_category_counts,
+            serializer,
+        );
     }
 }
 
@@ -846,10 +3355,70 @@ impl SseEncode for crate::api::search_engine::SearchResult {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.title, serializer);
         <String>::sse_encode(self.text, serializer);
+        <Vec<crate::api::search_engine::Highlight>>::sse_encode(self.highlights, serializer);
+        <Option<String>>::sse_encode(self.html, serializer);
         <u64>::sse_encode(self.id, serializer);
         <u64>::sse_encode(self.segment, serializer);
         <bool>::sse_encode(self.is_pdf, serializer);
         <String>::sse_encode(self.file_path, serializer);
+        <crate::api::search_engine::SearchCursor>::sse_encode(self.cursor, serializer);
+        <Vec<crate::api::search_engine::NeighborSegment>>::sse_encode(self.preceding, serializer);
+        <Vec<crate::api::search_engine::NeighborSegment>>::sse_encode(self.following, serializer);
+    }
+}
+
+impl SseEncode for crate::api::search_engine::SegmentHighlights {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.segment, serializer);
+        <Vec<crate::api::search_engine::Highlight>>::sse_encode(self.highlights, serializer);
+    }
+}
+
+impl SseEncode for crate::api::search_engine::SnippetBoundary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::search_engine::SnippetBoundary::None => 0,
+                crate::api::search_engine::SnippetBoundary::Word => 1,
+                crate::api::search_engine::SnippetBoundary::Sentence => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::search_engine::SnippetOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.max_chars, serializer);
+        <u32>::sse_encode(self.fragments, serializer);
+        <String>::sse_encode(self.separator, serializer);
+        <crate::api::search_engine::SnippetBoundary>::sse_encode(self.boundary, serializer);
+        <Option<crate::api::search_engine::HighlightTags>>::sse_encode(self.html_tags, serializer);
+    }
+}
+
+impl SseEncode for crate::api::search_engine::SortOrder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::search_engine::SortOrder::Relevance => 0,
+                crate::api::search_engine::SortOrder::LibraryOrder => 1,
+                crate::api::search_engine::SortOrder::LibraryOrderDescending => 2,
+                crate::api::search_engine::SortOrder::Title => 3,
+                crate::api::search_engine::SortOrder::TitleDescending => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
@@ -889,13 +3458,6 @@ impl SseEncode for usize {
     }
 }
 
-impl SseEncode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
-    }
-}
-
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
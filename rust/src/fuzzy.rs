@@ -0,0 +1,126 @@
+use crate::analysis;
+use levenshtein_automata::{Distance, LevenshteinAutomatonBuilder, DFA, SINK_STATE};
+use std::collections::BTreeSet;
+use tantivy::query::{AutomatonWeight, EnableScoring, Query, Weight};
+use tantivy::{Searcher, Term};
+use tantivy_fst::Automaton;
+
+/// Most indexed words listed for a single fuzzy word.
+pub const MAX_EXPANSIONS: usize = 50;
+
+/// Query for the words within `distance` edits of a term, with a
+/// transposition counting as one, like `FuzzyTermQuery`, except that marks
+/// are never edited: an unmarked term only matches words as written, and a
+/// marked one only forms with the same mark.
+#[derive(Clone, Debug)]
+pub struct FuzzyQuery {
+    term: Term,
+    distance: u8,
+}
+
+impl FuzzyQuery {
+    pub fn new(term: Term, distance: u8) -> Self {
+        FuzzyQuery { term, distance }
+    }
+}
+
+impl Query for FuzzyQuery {
+    fn weight(&self, _enable_scoring: EnableScoring<'_>) -> tantivy::Result<Box<dyn Weight>> {
+        let automaton = LevenshteinAutomaton::new(&self.term, self.distance);
+        Ok(Box::new(AutomatonWeight::new(self.term.field(), automaton)))
+    }
+}
+
+/// Tells the words a [`FuzzyQuery`] for a term matches, for finding them in
+/// text.
+pub struct FuzzyMatcher(LevenshteinAutomaton);
+
+impl FuzzyMatcher {
+    pub fn new(term: &Term, distance: u8) -> Self {
+        FuzzyMatcher(LevenshteinAutomaton::new(term, distance))
+    }
+
+    pub fn matches(&self, word: &str) -> bool {
+        let automaton = &self.0;
+        let mut state = automaton.start();
+        for byte in word.bytes() {
+            state = automaton.accept(&state, byte);
+            if !automaton.can_match(&state) {
+                return false;
+            }
+        }
+        automaton.is_match(&state)
+    }
+}
+
+/// Matches the words within a Levenshtein distance of the text of a term,
+/// the way `FuzzyTermQuery` does, after the mark of the term if it has one,
+/// and otherwise among unmarked words only.
+struct LevenshteinAutomaton {
+    dfa: DFA,
+    mark: Option<u8>,
+}
+
+impl LevenshteinAutomaton {
+    fn new(term: &Term, distance: u8) -> Self {
+        let value = term.value();
+        let word = value.as_str().unwrap_or_default();
+        let builder = LevenshteinAutomatonBuilder::new(distance, true);
+        LevenshteinAutomaton {
+            dfa: builder.build_dfa(analysis::unmarked(word)),
+            mark: word.bytes().next().filter(|_| analysis::is_marked(word)),
+        }
+    }
+}
+
+impl Automaton for LevenshteinAutomaton {
+    /// The state of the DFA, `None` before the first byte is read.
+    type State = Option<u32>;
+
+    fn start(&self) -> Option<u32> {
+        None
+    }
+
+    fn is_match(&self, state: &Option<u32>) -> bool {
+        let state = match (state, self.mark) {
+            (Some(state), _) => *state,
+            (None, None) => self.dfa.initial_state(),
+            (None, Some(_)) => return false,
+        };
+        matches!(self.dfa.distance(state), Distance::Exact(_))
+    }
+
+    fn can_match(&self, state: &Option<u32>) -> bool {
+        *state != Some(SINK_STATE)
+    }
+
+    fn accept(&self, state: &Option<u32>, byte: u8) -> Option<u32> {
+        Some(match (state, self.mark) {
+            (Some(state), _) => self.dfa.transition(*state, byte),
+            (None, Some(mark)) if byte == mark => self.dfa.initial_state(),
+            (None, None) if !analysis::is_mark(byte as char) => {
+                self.dfa.transition(self.dfa.initial_state(), byte)
+            }
+            (None, _) => SINK_STATE,
+        })
+    }
+}
+
+/// The indexed words a fuzzy query for `term` matches, that is those within
+/// `distance` edits of it with a transposition counting as one, in sorted
+/// order and at most [`MAX_EXPANSIONS`] of them. The forms of a marked term
+/// are listed without their mark.
+pub fn expansions(searcher: &Searcher, term: &Term, distance: u8) -> tantivy::Result<Vec<String>> {
+    let mut words = BTreeSet::new();
+    for segment_reader in searcher.segment_readers() {
+        let inverted_index = segment_reader.inverted_index(term.field())?;
+        let automaton = LevenshteinAutomaton::new(term, distance);
+        let mut stream = inverted_index.terms().search(automaton).into_stream()?;
+        while stream.advance() {
+            if let Ok(word) = std::str::from_utf8(stream.key()) {
+                words.insert(analysis::unmarked(word).to_string());
+            }
+        }
+    }
+    Ok(words.into_iter().take(MAX_EXPANSIONS).collect())
+}
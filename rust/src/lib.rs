@@ -1,2 +1,6 @@
+mod analysis;
 pub mod api;
 mod frb_generated;
+mod fuzzy;
+mod snippet;
+mod value_counts;
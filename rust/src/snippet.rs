@@ -0,0 +1,286 @@
+use crate::analysis::{self, NormalizedText};
+use crate::api::search_engine::{SnippetBoundary, SnippetOptions};
+use std::collections::BTreeMap;
+use std::ops::Range;
+use tantivy::tokenizer::TextAnalyzer;
+use tantivy::Score;
+
+/// Characters ending a sentence, the Hebrew sof pasuq among them.
+const SENTENCE_ENDS: &[char] = &['.', ':', '?', '!', '׃'];
+
+/// A part of a text shown around the words matched in it.
+pub struct Fragment {
+    /// Where the fragment lies in the text, in bytes.
+    pub range: Range<usize>,
+    /// The matched words of the fragment, in bytes of the text, each with
+    /// the term it matched.
+    pub matches: Vec<(Range<usize>, String)>,
+}
+
+/// The fragments of `plain`, the plain text of the stored `text`, best
+/// showing the words whose analysis by `analyzer` gives a term `terms`
+/// scores, in the order of the text. The score of a fragment is that of the
+/// distinct terms it matches, and each is shaped as `options` asks. Empty
+/// when no word matches.
+pub fn fragments(
+    text: &str,
+    plain: &NormalizedText,
+    analyzer: &mut TextAnalyzer,
+    terms: &dyn Fn(&str) -> Option<Score>,
+    options: &SnippetOptions,
+) -> Vec<Fragment> {
+    let words: Vec<(Range<usize>, String, Score)> = matched_words(text, analyzer, terms)
+        .into_iter()
+        .map(|(word, term, score)| (plain.normalized_range(word), term, score))
+        .collect();
+    let text = plain.as_str();
+    let max_chars = options.max_chars.max(1) as usize;
+    let chars = |range: Range<usize>| text[range].chars().count();
+
+    // runs of matched words fitting in a fragment
+    let mut runs: Vec<Range<usize>> = Vec::new();
+    for index in 0..words.len() {
+        match runs.last_mut() {
+            Some(run) if chars(words[run.start].0.start..words[index].0.end) <= max_chars => {
+                run.end = index + 1
+            }
+            _ => runs.push(index..index + 1),
+        }
+    }
+    let score = |run: &Range<usize>| -> Score {
+        let terms_matched: BTreeMap<&str, Score> = words[run.clone()]
+            .iter()
+            .map(|(_, term, score)| (term.as_str(), *score))
+            .collect();
+        terms_matched.values().sum()
+    };
+    let mut best: Vec<Range<usize>> = runs;
+    // the sort is stable, so of equal runs the first ones are kept
+    best.sort_by(|a, b| score(b).total_cmp(&score(a)));
+    best.truncate(options.fragments.max(1) as usize);
+    best.sort_by_key(|run| run.start);
+
+    let mut fragments = Vec::new();
+    let mut previous_end = 0;
+    for (index, run) in best.iter().enumerate() {
+        let span = words[run.start].0.start..words[run.end - 1].0.end;
+        let next_start = best
+            .get(index + 1)
+            .map_or(text.len(), |next| words[next.start].0.start);
+        let bounds = previous_end..next_start;
+        let range = match options.boundary {
+            SnippetBoundary::Sentence => sentence(text, &span, &bounds)
+                .filter(|sentence| chars(sentence.clone()) <= max_chars)
+                .unwrap_or_else(|| {
+                    whole_words(text, window(text, &span, &bounds, max_chars), &span)
+                }),
+            SnippetBoundary::Word => {
+                whole_words(text, window(text, &span, &bounds, max_chars), &span)
+            }
+            SnippetBoundary::None => window(text, &span, &bounds, max_chars),
+        };
+        previous_end = range.end;
+        let matches = words[run.clone()]
+            .iter()
+            .map(|(word, term, _)| (word.clone(), analysis::unmarked(term).to_string()))
+            .collect();
+        fragments.push(Fragment { range, matches });
+    }
+    fragments
+}
+
+/// All of `text` as one fragment, with every word matched in it.
+pub fn whole(
+    text: &str,
+    analyzer: &mut TextAnalyzer,
+    terms: &dyn Fn(&str) -> Option<Score>,
+) -> Fragment {
+    let matches = matched_words(text, analyzer, terms)
+        .into_iter()
+        .map(|(word, term, _)| (word, analysis::unmarked(&term).to_string()))
+        .collect();
+    Fragment {
+        range: 0..text.len(),
+        matches,
+    }
+}
+
+/// The words of `text` whose analysis by `analyzer` gives a term `terms`
+/// scores, in order, each with the term and its score.
+fn matched_words(
+    text: &str,
+    analyzer: &mut TextAnalyzer,
+    terms: &dyn Fn(&str) -> Option<Score>,
+) -> Vec<(Range<usize>, String, Score)> {
+    let mut matches: Vec<(Range<usize>, String, Score)> = Vec::new();
+    analyzer.token_stream(text).process(&mut |token| {
+        if let Some(score) = terms(&token.text) {
+            let range = token.offset_from..token.offset_to;
+            matches.push((range, token.text.clone(), score));
+        }
+    });
+    // words matching several forms, or within a matched phrase, are matched once
+    matches.sort_by_key(|(range, ..)| (range.start, range.end));
+    let mut words: Vec<(Range<usize>, String, Score)> = Vec::new();
+    for (range, term, score) in matches {
+        match words.last_mut() {
+            Some((word, ..)) if range.start < word.end => word.end = word.end.max(range.end),
+            _ => words.push((range, term, score)),
+        }
+    }
+    words
+}
+
+/// `span` widened by the same number of characters on either side, as far
+/// as `bounds` allow, to `max_chars` characters.
+fn window(
+    text: &str,
+    span: &Range<usize>,
+    bounds: &Range<usize>,
+    max_chars: usize,
+) -> Range<usize> {
+    let slack = max_chars.saturating_sub(text[span.clone()].chars().count());
+    let before = text[bounds.start..span.start].chars().count();
+    let after = text[span.end..bounds.end.max(span.end)].chars().count();
+    let right = after.min(slack - (slack / 2).min(before));
+    let left = before.min(slack - right);
+    let start = match left {
+        0 => span.start,
+        _ => text[..span.start]
+            .char_indices()
+            .rev()
+            .nth(left - 1)
+            .map_or(0, |(start, _)| start),
+    };
+    let end = text[span.end..]
+        .char_indices()
+        .nth(right)
+        .map_or(text.len(), |(end, _)| span.end + end);
+    start..end
+}
+
+/// `range` narrowed to the words it holds whole, never past `span`, and
+/// without surrounding spaces.
+fn whole_words(text: &str, range: Range<usize>, span: &Range<usize>) -> Range<usize> {
+    let mut start = range.start;
+    if text[..start].ends_with(|c: char| !c.is_whitespace()) {
+        start = text[start..span.start]
+            .find(char::is_whitespace)
+            .map_or(span.start, |space| start + space);
+    }
+    let mut end = range.end;
+    if text[end..].starts_with(|c: char| !c.is_whitespace()) {
+        end = text[span.end..end]
+            .rfind(char::is_whitespace)
+            .map_or(span.end, |space| span.end + space);
+    }
+    let trimmed = text[start..end].trim_start();
+    start = end - trimmed.len();
+    end = start + trimmed.trim_end().len();
+    start..end
+}
+
+/// The sentences holding `span`, within `bounds`.
+fn sentence(text: &str, span: &Range<usize>, bounds: &Range<usize>) -> Option<Range<usize>> {
+    let after = |end: usize| end + text[end..].chars().next().map_or(0, char::len_utf8);
+    let start = text[bounds.start..span.start]
+        .rfind(SENTENCE_ENDS)
+        .map_or(bounds.start, |end| after(bounds.start + end));
+    let end = text[span.end..bounds.end.max(span.end)]
+        .find(SENTENCE_ENDS)
+        .map_or(bounds.end.max(span.end), |end| after(span.end + end));
+    // a sentence cut off by another fragment is not whole
+    if (start == bounds.start && bounds.start > 0) || (end == bounds.end && bounds.end < text.len())
+    {
+        return None;
+    }
+    let trimmed = text[start..end].trim_start();
+    Some(end - trimmed.len()..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fragments;
+    use crate::analysis;
+    use crate::api::search_engine::{SnippetBoundary, SnippetOptions};
+    use tantivy::tokenizer::{SimpleTokenizer, TextAnalyzer};
+
+    /// The fragments of `text` shaped by the other arguments, showing the
+    /// words "target" and, scoring higher, "rare".
+    fn cut(text: &str, max_chars: u32, count: u32, boundary: SnippetBoundary) -> Vec<String> {
+        let options = SnippetOptions {
+            max_chars,
+            fragments: count,
+            boundary,
+            ..Default::default()
+        };
+        let plain = analysis::plain_text(text);
+        let mut analyzer = TextAnalyzer::from(SimpleTokenizer::default());
+        let terms = |term: &str| match term {
+            "target" => Some(1.0),
+            "rare" => Some(2.0),
+            _ => None,
+        };
+        fragments(text, &plain, &mut analyzer, &terms, &options)
+            .into_iter()
+            .map(|fragment| plain.as_str()[fragment.range].to_string())
+            .collect()
+    }
+
+    const WORDS: &str = "one two three four five six target seven eight nine ten";
+
+    #[test]
+    fn fragments_are_centered_within_max_chars() {
+        assert_eq!(
+            cut(WORDS, 20, 1, SnippetBoundary::None),
+            ["ve six target seven "]
+        );
+        assert_eq!(
+            cut(WORDS, 20, 1, SnippetBoundary::Word),
+            ["six target seven"]
+        );
+        // a fragment shorter than its words holds them all the same
+        assert_eq!(cut(WORDS, 3, 1, SnippetBoundary::Word), ["target"]);
+        assert_eq!(cut(WORDS, 100, 1, SnippetBoundary::Word), [WORDS]);
+        assert!(cut(WORDS, 20, 1, SnippetBoundary::Word)
+            .iter()
+            .all(|fragment| fragment.chars().count() <= 20));
+    }
+
+    #[test]
+    fn fragments_end_at_sentences_that_fit() {
+        let text = "It begins here. The target is in this one. It ends here.";
+        assert_eq!(
+            cut(text, 30, 1, SnippetBoundary::Sentence),
+            ["The target is in this one."]
+        );
+        // a sentence too long for the fragment is cut between words
+        assert_eq!(
+            cut(text, 15, 1, SnippetBoundary::Sentence),
+            ["The target is"]
+        );
+    }
+
+    #[test]
+    fn the_best_fragments_come_in_text_order() {
+        let text = "target one two three four five six seven eight rare";
+        // the rarer word scores higher
+        assert_eq!(cut(text, 10, 1, SnippetBoundary::Word), ["eight rare"]);
+        assert_eq!(
+            cut(text, 10, 2, SnippetBoundary::Word),
+            ["target one", "eight rare"]
+        );
+        assert_eq!(
+            cut(text, 10, 5, SnippetBoundary::Word),
+            ["target one", "eight rare"]
+        );
+        assert!(cut(text, 10, 1, SnippetBoundary::None)
+            .iter()
+            .all(|fragment| fragment.contains("rare")));
+    }
+
+    #[test]
+    fn nothing_matched_gives_no_fragments() {
+        assert!(cut("one two three", 10, 1, SnippetBoundary::Word).is_empty());
+    }
+}
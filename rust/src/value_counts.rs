@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+use tantivy::collector::{Collector, SegmentCollector};
+use tantivy::columnar::StrColumn;
+use tantivy::{DocId, Score, SegmentOrdinal, SegmentReader};
+
+/// Counts the hits by each value of a fast string field, such as the book
+/// `title`. Facet values come encoded, see `Facet::from_encoded`.
+pub struct ValueCounts {
+    field: &'static str,
+}
+
+impl ValueCounts {
+    pub fn new(field: &'static str) -> Self {
+        ValueCounts { field }
+    }
+}
+
+pub struct SegmentValueCounts {
+    values: Option<StrColumn>,
+    // hits by value ordinal within the segment
+    counts: Vec<u64>,
+}
+
+impl Collector for ValueCounts {
+    type Fruit = HashMap<String, u64>;
+    type Child = SegmentValueCounts;
+
+    fn for_segment(
+        &self,
+        _segment_local_id: SegmentOrdinal,
+        segment: &SegmentReader,
+    ) -> tantivy::Result<SegmentValueCounts> {
+        let values = segment.fast_fields().str(self.field)?;
+        let counts = vec![0; values.as_ref().map_or(0, |values| values.num_terms())];
+        Ok(SegmentValueCounts { values, counts })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        false
+    }
+
+    fn merge_fruits(
+        &self,
+        segment_fruits: Vec<HashMap<String, u64>>,
+    ) -> tantivy::Result<HashMap<String, u64>> {
+        let mut counts = HashMap::new();
+        for (value, count) in segment_fruits.into_iter().flatten() {
+            *counts.entry(value).or_default() += count;
+        }
+        Ok(counts)
+    }
+}
+
+impl SegmentCollector for SegmentValueCounts {
+    type Fruit = HashMap<String, u64>;
+
+    fn collect(&mut self, doc: DocId, _score: Score) {
+        if let Some(values) = &self.values {
+            for ord in values.term_ords(doc) {
+                self.counts[ord as usize] += 1;
+            }
+        }
+    }
+
+    fn harvest(self) -> HashMap<String, u64> {
+        let mut counts = HashMap::new();
+        let Some(values) = self.values else {
+            return counts;
+        };
+        for (ord, count) in self.counts.into_iter().enumerate() {
+            let mut value = String::new();
+            if count > 0 && values.ord_to_str(ord as u64, &mut value).unwrap_or(false) {
+                counts.insert(value, count);
+            }
+        }
+        counts
+    }
+}